@@ -0,0 +1,123 @@
+mod aliased_field_error;
+mod aliased_fields;
+mod duplicate_options;
+
+pub use aliased_field_error::AliasedFieldError;
+pub use aliased_fields::AliasedFields;
+pub use duplicate_options::DuplicateOptions;
+
+pub use duplicate_options::DUPLICATES_ATTRIBUTE_PATH;
+use proc_macro2::{Ident, Span};
+use syn::{Field, LitStr, Type};
+
+use crate::duplicate_strategy::DuplicateStrategy;
+
+const SERDE_ATTRIBUTE_PATH: &str = "serde";
+
+const SERDE_FIELD_ATTRIBUTE_PATHS: [&str; 2] = ["alias", "rename"];
+
+/// # Aliased Field
+///
+/// A field which has been annotated with #[serde(alias = "xyz")] or #[serde(rename = "xyz")]
+pub struct AliasedField {
+    /// The original field's identifier
+    pub field_identifier: Ident,
+
+    /// The original field's type
+    pub field_type: Type,
+
+    /// A list of alias names
+    pub names: Vec<LitStr>,
+
+    /// The name the field is serialized under
+    pub serialized_name: LitStr,
+
+    /// Should this value be deserialized and use the default value on error
+    pub uses_default: bool,
+
+    /// Options set through the #[duplicates(...)] attribute
+    pub options: DuplicateOptions,
+}
+
+impl TryFrom<Field> for AliasedField {
+    type Error = AliasedFieldError;
+
+    fn try_from(value: Field) -> Result<Self, Self::Error> {
+        let field_identifier = value.ident.ok_or(AliasedFieldError::UnnamedField)?;
+
+        let mut names = vec![LitStr::new(
+            &field_identifier.to_string(),
+            Span::call_site(),
+        )];
+
+        let mut serialized_name = None;
+
+        let mut uses_default = Default::default();
+
+        let mut options = DuplicateOptions::default();
+
+        value
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident(DUPLICATES_ATTRIBUTE_PATH))
+            .try_for_each(|attribute| attribute.parse_nested_meta(|meta| options.parse_meta(meta)))?;
+
+        value
+            .attrs
+            .into_iter()
+            .filter(|attribute| attribute.path().is_ident(SERDE_ATTRIBUTE_PATH))
+            .try_for_each(|attribute| {
+                attribute.parse_nested_meta(|meta| {
+                    if SERDE_FIELD_ATTRIBUTE_PATHS
+                        .iter()
+                        .any(|path| meta.path.is_ident(path))
+                    {
+                        let name: LitStr = meta.value()?.parse()?;
+
+                        if meta.path.is_ident("rename") {
+                            serialized_name = Some(name.clone());
+                        }
+
+                        names.push(name);
+
+                        return Ok(());
+                    }
+
+                    if meta.path.is_ident("default") {
+                        uses_default = true;
+
+                        return Ok(())
+                    }
+
+                    // Fields capturing unknown keys are already flattened when deserializing, serialization may flatten them too
+                    if meta.path.is_ident("flatten") && options.captures_unknown_keys() {
+                        return Ok(())
+                    }
+
+                    Err(meta.error("Unsupported serde attribute"))
+                })
+            })?;
+
+        // Each occurrence of a collected field is only one element of its value
+        if options.strategy.as_ref().is_some_and(DuplicateStrategy::keeps_every_occurrence)
+            && (options.map_keys.is_some() || options.buffers_occurrences())
+        {
+            return Err(syn::Error::new(
+                field_identifier.span(),
+                "collect and raw cannot be combined with skip_invalid, default_on_error or map_keys",
+            )
+            .into());
+        }
+
+        let serialized_name = serialized_name.unwrap_or_else(|| names[0].clone());
+
+        Ok(Self {
+            field_identifier,
+            field_type: value.ty,
+            names,
+            serialized_name,
+            uses_default,
+            options,
+        })
+    }
+}
@@ -11,7 +11,12 @@ pub enum AliasedFieldError {
 
     /// Wrapper for [Errors from the Syn crate](syn::Error)
     SynError(syn::Error),
-    
+
+    /// Occurs if a field refers to another field which does not exist or is not read from the data
+    UnknownField(String),
+
+    /// Occurs if more than one field captures the entries whose keys match no field
+    MultipleUnknownKeyFields,
 }
 
 impl Display for AliasedFieldError {
@@ -21,7 +26,9 @@ impl Display for AliasedFieldError {
             "{}",
             match self {
                 Self::UnnamedField => "Struct must at least have named fields for duplicated name checking to take place".to_owned(),
-                Self::SynError(err) => format!("Error parsing arguments: {}", err)
+                Self::SynError(err) => format!("Error parsing arguments: {}", err),
+                Self::UnknownField(name) => format!("`{}` does not name a field read from the data", name),
+                Self::MultipleUnknownKeyFields => "Only one field may capture unknown keys".to_owned(),
             }
         )
     }
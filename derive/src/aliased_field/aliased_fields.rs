@@ -1,40 +1,61 @@
-use syn::{Ident, LitStr};
+use syn::{Ident, LitStr, Type};
 
-use super::AliasedField;
+use super::{AliasedField, DuplicateOptions};
 
 #[derive(Debug, Clone)]
 pub struct AliasedFields {
     /// The original field's identifier
     pub field_identifiers: Vec<Ident>,
 
+    /// The original field's types
+    pub field_types: Vec<Type>,
+
     /// A list of alias names
     pub names_list: Vec<Vec<LitStr>>,
 
+    /// The names each field is serialized under
+    pub serialized_names: Vec<LitStr>,
+
     /// A list of defaultable names
     pub uses_default_selections: Vec<bool>,
+
+    /// A list of options set through the #[duplicates(...)] attribute
+    pub options_list: Vec<DuplicateOptions>,
 }
 
 impl From<Vec<AliasedField>> for AliasedFields {
     fn from(fields: Vec<AliasedField>) -> Self {
         let mut field_identifiers = Vec::new();
+        let mut field_types = Vec::new();
         let mut names_list = Vec::new();
+        let mut serialized_names = Vec::new();
         let mut uses_default_selections = Vec::new();
+        let mut options_list = Vec::new();
 
         for AliasedField {
             field_identifier,
+            field_type,
             names,
-            uses_default
+            serialized_name,
+            uses_default,
+            options,
         } in fields
         {
             field_identifiers.push(field_identifier);
+            field_types.push(field_type);
             names_list.push(names);
+            serialized_names.push(serialized_name);
             uses_default_selections.push(uses_default);
+            options_list.push(options);
         }
 
         Self {
             field_identifiers,
+            field_types,
             names_list,
+            serialized_names,
             uses_default_selections,
+            options_list,
         }
     }
 }
@@ -0,0 +1,209 @@
+use syn::{meta::ParseNestedMeta, Ident, LitInt, LitStr, Path, Token};
+
+use crate::duplicate_strategy::{DuplicateStrategy, TieBreaker};
+use crate::policy::Policy;
+
+/// The path of the attribute used to configure duplicate handling for a field
+pub const DUPLICATES_ATTRIBUTE_PATH: &str = "duplicates";
+
+/// # Duplicate Options
+///
+/// Options set through the #[duplicates(...)] attribute of a field
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateOptions {
+    /// Should occurrences which fail to deserialize be skipped in favour of the next valid occurrence
+    pub skip_invalid: bool,
+
+    /// Should the default value be used when the selected occurrence fails to deserialize
+    pub default_on_error: bool,
+
+    /// A strategy overriding the one chosen by the derive macro for this field
+    pub strategy: Option<DuplicateStrategy>,
+
+    /// Is this field populated with the warnings raised while deserializing the other fields
+    pub warnings: bool,
+
+    /// The field whose selected occurrence's key this field is populated with
+    pub source_of: Option<Ident>,
+
+    /// The field whose number of occurrences this field is populated with
+    pub count_of: Option<Ident>,
+
+    /// The policy applied to duplicate keys within the map this field holds
+    pub map_keys: Option<Policy>,
+
+    /// Is this field populated with every entry whose key matches no other field
+    pub rest: bool,
+
+    /// Is this field populated with the entries whose key matches no other field, following the struct's duplicate policy
+    pub extra: bool,
+
+    /// Is this field populated with the key of every entry, in the order they were found
+    pub key_order: bool,
+}
+
+impl DuplicateOptions {
+    /// Apply a single nested meta item of a #[duplicates(...)] attribute to these options
+    pub fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("skip_invalid") {
+            self.skip_invalid = true;
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("default_on_error") {
+            self.default_on_error = true;
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("warnings") {
+            self.warnings = true;
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("rest") {
+            self.rest = true;
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("key_order") {
+            self.key_order = true;
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("extra") {
+            self.extra = true;
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("source_of") {
+            self.source_of = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("count_of") {
+            self.count_of = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("map_keys") {
+            self.map_keys = Some(Policy::try_from(&meta.value()?.parse::<LitStr>()?)?);
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("merge") {
+            return self.set_strategy(&meta, DuplicateStrategy::Merge);
+        }
+
+        if meta.path.is_ident("extend") {
+            return self.set_strategy(&meta, DuplicateStrategy::Extend);
+        }
+
+        if meta.path.is_ident("collect") {
+            return self.set_strategy(&meta, DuplicateStrategy::Collect);
+        }
+
+        if meta.path.is_ident("raw") {
+            return self.set_strategy(&meta, DuplicateStrategy::Raw);
+        }
+
+        if meta.path.is_ident("sum") {
+            return self.set_strategy(&meta, DuplicateStrategy::Sum);
+        }
+
+        if meta.path.is_ident("min") {
+            return self.set_strategy(&meta, DuplicateStrategy::Min);
+        }
+
+        if meta.path.is_ident("max") {
+            return self.set_strategy(&meta, DuplicateStrategy::Max);
+        }
+
+        if meta.path.is_ident("majority") {
+            let tie_breaker = if meta.input.is_empty() || meta.input.peek(Token![,]) {
+                TieBreaker::First
+            } else {
+                let tie_breaker = meta.value()?.parse::<LitStr>()?;
+
+                match tie_breaker.value().as_str() {
+                    "first" => TieBreaker::First,
+                    "last" => TieBreaker::Last,
+                    "error" => TieBreaker::Error,
+                    _ => {
+                        return Err(syn::Error::new(
+                            tie_breaker.span(),
+                            "Expected a tie breaker of \"first\", \"last\" or \"error\"",
+                        ))
+                    }
+                }
+            };
+
+            return self.set_strategy(&meta, DuplicateStrategy::Majority(tie_breaker));
+        }
+
+        if meta.path.is_ident("nth") {
+            let value = meta.value()?;
+
+            let is_negative = value.parse::<Option<Token![-]>>()?.is_some();
+
+            let position = value.parse::<LitInt>()?;
+
+            let position = match position.base10_parse::<isize>()? {
+                0 => return Err(syn::Error::new(position.span(), "Occurrence positions start at 1, or -1 for the last occurrence")),
+                position if is_negative => -position,
+                position => position,
+            };
+
+            return self.set_strategy(&meta, DuplicateStrategy::Nth(position));
+        }
+
+        if meta.path.is_ident("resolve") {
+            let resolver = meta.value()?.parse::<LitStr>()?.parse::<Path>()?;
+
+            return self.set_strategy(&meta, DuplicateStrategy::Resolve(resolver));
+        }
+
+        Err(meta.error("Unsupported duplicates attribute"))
+    }
+
+    /// Set the strategy of this field, ensuring only one is given
+    fn set_strategy(&mut self, meta: &ParseNestedMeta, strategy: DuplicateStrategy) -> syn::Result<()> {
+        if self.strategy.is_some() {
+            return Err(meta.error("Only one duplicate strategy may be given per field"));
+        }
+
+        self.strategy = Some(strategy);
+
+        Ok(())
+    }
+
+    /// Is this field populated with information about the other fields rather than read from the data
+    pub fn is_metadata(&self) -> bool {
+        self.warnings || self.key_order || self.captures_unknown_keys() || self.referenced_field().is_some()
+    }
+
+    /// Is this field populated with the entries whose key matches no other field
+    pub fn captures_unknown_keys(&self) -> bool {
+        self.rest || self.extra
+    }
+
+    /// The field this field describes, if any
+    pub fn referenced_field(&self) -> Option<&Ident> {
+        self.source_of.as_ref().or(self.count_of.as_ref())
+    }
+
+    /// Do the occurrences of this field need to be buffered before one can be selected
+    pub fn buffers_occurrences(&self) -> bool {
+        self.skip_invalid
+            || self.default_on_error
+            || self.strategy.as_ref().is_some_and(DuplicateStrategy::buffers_occurrences)
+    }
+}
@@ -0,0 +1,54 @@
+use syn::{meta::ParseNestedMeta, Attribute, LitStr};
+
+use crate::aliased_field::DUPLICATES_ATTRIBUTE_PATH;
+use crate::policy::Policy;
+
+/// # Container Options
+///
+/// Options set through the #[duplicates(...)] attribute of a struct
+#[derive(Debug, Clone, Default)]
+pub struct ContainerOptions {
+    /// A policy replacing the one chosen by the derive macro for every field without a strategy of its own
+    pub policy: Option<Policy>,
+
+    /// Should the struct's policy also govern every struct nested within it
+    pub recursive: bool,
+}
+
+impl ContainerOptions {
+    /// Apply a single nested meta item of a #[duplicates(...)] attribute to these options
+    pub fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("recursive") {
+            self.recursive = true;
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("strategy") {
+            if self.policy.is_some() {
+                return Err(meta.error("Only one duplicate strategy may be given per struct"));
+            }
+
+            self.policy = Some(Policy::try_from(&meta.value()?.parse::<LitStr>()?)?);
+
+            return Ok(());
+        }
+
+        Err(meta.error("Unsupported duplicates attribute"))
+    }
+}
+
+impl TryFrom<&[Attribute]> for ContainerOptions {
+    type Error = syn::Error;
+
+    fn try_from(value: &[Attribute]) -> Result<Self, Self::Error> {
+        let mut options = ContainerOptions::default();
+
+        value
+            .iter()
+            .filter(|attribute| attribute.path().is_ident(DUPLICATES_ATTRIBUTE_PATH))
+            .try_for_each(|attribute| attribute.parse_nested_meta(|meta| options.parse_meta(meta)))?;
+
+        Ok(options)
+    }
+}
@@ -0,0 +1,86 @@
+use syn::Path;
+
+/// # Duplicate Strategy
+///
+/// Describes which occurrence of a duplicated key is used to populate a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DuplicateStrategy {
+    /// Use the first occurrence found, ignoring the rest
+    First,
+
+    /// Use the last occurrence found, overwriting any previous ones
+    Last,
+
+    /// Deep-merge every occurrence following JSON Merge Patch semantics, later occurrences overriding earlier ones
+    Merge,
+
+    /// Extend the collection deserialized from the first occurrence with every later one
+    Extend,
+
+    /// Pass every occurrence, paired with the key it was found under, to a user supplied function choosing the value
+    Resolve(Path),
+
+    /// Add every occurrence together
+    Sum,
+
+    /// Use the smallest occurrence
+    Min,
+
+    /// Use the largest occurrence
+    Max,
+
+    /// Use the most frequent occurrence, settling ties with the given [TieBreaker]
+    Majority(TieBreaker),
+
+    /// Use the occurrence at the given one-based position, counting back from the end when negative
+    Nth(isize),
+
+    /// Keep every occurrence, in order, deserializing the field as a sequence of them
+    Collect,
+
+    /// Keep every occurrence, in order, as the raw JSON text it was received as
+    Raw,
+}
+
+/// # Tie Breaker
+///
+/// Describes how a tie between equally frequent occurrences is settled by [DuplicateStrategy::Majority]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreaker {
+    /// Use the tied value which was seen first
+    First,
+
+    /// Use the tied value which was seen last
+    Last,
+
+    /// Fail deserialization
+    Error,
+}
+
+impl DuplicateStrategy {
+    /// Does this strategy need every occurrence to be buffered before a value can be produced
+    pub fn buffers_occurrences(&self) -> bool {
+        // Counting back from the end, the selected occurrence is only known once every one was found
+        matches!(self, Self::Merge) || matches!(self, Self::Nth(position) if *position < 0)
+    }
+
+    /// Does this strategy need every deserialized occurrence to be collected before a value can be produced
+    pub fn collects_occurrences(&self) -> bool {
+        matches!(self, Self::Resolve(_) | Self::Sum | Self::Min | Self::Max | Self::Majority(_))
+    }
+
+    /// Are the occurrences other than the one selected thrown away by this strategy
+    pub fn discards_occurrences(&self) -> bool {
+        matches!(self, Self::First | Self::Last | Self::Nth(_))
+    }
+
+    /// Is every occurrence kept as its own element of the field's value
+    pub fn keeps_every_occurrence(&self) -> bool {
+        matches!(self, Self::Collect | Self::Raw)
+    }
+
+    /// Is the value of a field taken from a single (possibly merged) occurrence rather than combined from all of them
+    pub fn selects_single_occurrence(&self) -> bool {
+        matches!(self, Self::First | Self::Last | Self::Merge | Self::Nth(_))
+    }
+}
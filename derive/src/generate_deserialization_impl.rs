@@ -0,0 +1,1096 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, LitStr, Type};
+
+use crate::aliased_field::{AliasedFields, DuplicateOptions};
+use crate::container_options::ContainerOptions;
+use crate::policy::Policy;
+use crate::duplicate_strategy::{DuplicateStrategy, TieBreaker};
+
+/// The parts of a single field needed to generate its deserialization
+struct FieldContext<'a> {
+    /// The original field's identifier, also used for the variable holding its occurrences
+    identifier: &'a Ident,
+
+    /// The original field's type
+    field_type: &'a Type,
+
+    /// The variable holding the key of every occurrence found for the field, in order
+    keys_identifier: Ident,
+
+    /// The variable holding the index of the occurrence used for the field, if a single one was used as is
+    selected_identifier: Ident,
+
+    /// A list of names the field may be found under
+    names: &'a [LitStr],
+
+    /// Should the default value be used if the field is missing
+    use_default: bool,
+
+    /// Options set through the #[duplicates(...)] attribute
+    options: &'a DuplicateOptions,
+
+    /// The strategy used for this field, either set on the field or chosen by the derive macro
+    strategy: DuplicateStrategy,
+
+    /// Can the strategy be replaced by a policy chosen at runtime, as none was set on the field
+    follows_policy: bool,
+
+    /// The variable holding every occurrence of the field when collected under a runtime policy
+    collected_identifier: Ident,
+
+    /// The variable holding the index of every buffered occurrence already reported as skipped for being invalid
+    skipped_identifier: Ident,
+
+    /// The variable noting that the default value was used as no buffered occurrence could be deserialized
+    defaulted_identifier: Ident,
+
+    /// Are warnings collected while deserializing the struct this field belongs to
+    reports_warnings: bool,
+
+    /// Is the key of every entry recorded while deserializing the struct this field belongs to
+    records_key_order: bool,
+
+    /// The name of the struct this field belongs to
+    type_name: String,
+}
+
+pub fn generate_deserialization_impl(
+    deserialization_target_type_identifier: Ident,
+    aliased_fields: AliasedFields,
+    container_options: ContainerOptions,
+    strategy: DuplicateStrategy,
+) -> proc_macro::TokenStream {
+    let AliasedFields {
+        field_identifiers: deserialization_target_field_identifiers,
+        field_types,
+        names_list,
+        uses_default_selections,
+        options_list,
+        ..
+    } = aliased_fields;
+
+    // A strategy set on the struct takes the place of the derive's own
+    let default_policy = container_options.policy.unwrap_or(match strategy {
+        DuplicateStrategy::Last => Policy::Last,
+        _ => Policy::First,
+    });
+
+    let strategy = match default_policy {
+        Policy::First => DuplicateStrategy::First,
+        Policy::Last => DuplicateStrategy::Last,
+        _ => strategy,
+    };
+
+    let reports_warnings = options_list.iter().any(|options| options.warnings);
+
+    let records_key_order = options_list.iter().any(|options| options.key_order);
+
+    let type_name = deserialization_target_type_identifier.to_string();
+
+    let field_contexts = deserialization_target_field_identifiers
+        .iter()
+        .zip(&field_types)
+        .zip(&names_list)
+        .zip(uses_default_selections)
+        .zip(&options_list)
+        .filter(|(_, options)| !options.is_metadata())
+        .map(|((((identifier, field_type), names), use_default), options)| FieldContext {
+            identifier,
+            field_type,
+            keys_identifier: keys_identifier(identifier),
+            selected_identifier: selected_identifier(identifier),
+            names,
+            use_default,
+            options,
+            strategy: options.strategy.clone().unwrap_or_else(|| strategy.clone()),
+            follows_policy: options.strategy.is_none(),
+            collected_identifier: format_ident!("__{}_collected", identifier.unraw()),
+            skipped_identifier: format_ident!("__{}_skipped", identifier.unraw()),
+            defaulted_identifier: format_ident!("__{}_defaulted", identifier.unraw()),
+            reports_warnings,
+            records_key_order,
+            type_name: type_name.clone(),
+        })
+        .collect::<Vec<FieldContext>>();
+
+    let declarations = field_contexts.iter().map(generate_declaration).collect::<Vec<TokenStream>>();
+
+    let key_value_mapping_patterns = field_contexts.iter().map(generate_match_arm).collect::<Vec<TokenStream>>();
+
+    let value_extractions = field_contexts.iter().map(generate_value_extraction).collect::<Vec<TokenStream>>();
+
+    // Metadata, such as warnings, is only complete once every other field has been extracted
+    let metadata_extractions = deserialization_target_field_identifiers
+        .iter()
+        .zip(&options_list)
+        .filter(|(_, options)| options.is_metadata())
+        .map(|(identifier, options)| generate_metadata_extraction(identifier, options, &field_contexts))
+        .collect::<Vec<TokenStream>>();
+
+    let captures_unknown_keys = options_list.iter().any(DuplicateOptions::captures_unknown_keys);
+
+    let captures_extra_keys = options_list.iter().any(|options| options.extra);
+
+    let unknown_entries_declaration = if captures_unknown_keys {
+        quote! {
+            let mut __unknown_entries: Vec<(
+                ::serde_deserialize_duplicates::__private::Content<'de>,
+                ::serde_deserialize_duplicates::__private::Content<'de>,
+            )> = Vec::new();
+        }
+    } else {
+        quote! {}
+    };
+
+    let unknown_entry_handling = if captures_unknown_keys {
+        quote! {
+            __unknown_entries.push((key.into(), map.next_value()?));
+        }
+    } else {
+        quote! {
+            map.next_value::<serde::de::IgnoredAny>()?;
+        }
+    };
+
+    let unknown_entry_recording = if records_key_order {
+        quote! {
+            __key_order.push((key.to_string(), None, 0));
+        }
+    } else {
+        quote! {}
+    };
+
+    let key_order_declaration = if records_key_order {
+        quote! {
+            // The key, field and occurrence index of every entry
+            let mut __key_order: Vec<(String, Option<&'static str>, usize)> = Vec::new();
+        }
+    } else {
+        quote! {}
+    };
+
+    let warnings_declaration = if reports_warnings {
+        quote! {
+            #[allow(unused_mut)]
+            let mut __duplicate_warnings: Vec<String> = Vec::new();
+        }
+    } else {
+        quote! {}
+    };
+
+    let policy_declaration = if container_options.recursive
+        || captures_extra_keys
+        || field_contexts.iter().any(|field| field.follows_policy)
+    {
+        // A strategy set on the struct is only replaced by a policy chosen at runtime, not one propagated by another struct
+        let overriding_policy = if container_options.policy.is_some() {
+            quote! { ::serde_deserialize_duplicates::__private::runtime_policy() }
+        } else {
+            quote! { ::serde_deserialize_duplicates::__private::current_policy() }
+        };
+
+        quote! {
+            let __policy = #overriding_policy.unwrap_or(#default_policy);
+        }
+    } else {
+        quote! {}
+    };
+
+    // Nested structs follow this struct's policy for as long as it is being deserialized
+    let policy_propagation = if container_options.recursive {
+        quote! {
+            let __policy_scope = ::serde_deserialize_duplicates::__private::PolicyScope::enter(__policy);
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        // Scope the visitor so several derives can live in the same module
+        const _: () = {
+            impl<'de> serde::Deserialize<'de> for #deserialization_target_type_identifier {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    // Buffered occurrences are deserialized as if they came from the format they were read from
+                    let human_readable = deserializer.is_human_readable();
+
+                    deserializer.deserialize_map(DuplicateVisitor { human_readable })
+                }
+            }
+
+            struct DuplicateVisitor {
+                human_readable: bool,
+            }
+
+            impl<'de> serde::de::Visitor<'de> for DuplicateVisitor {
+                type Value = #deserialization_target_type_identifier;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    formatter.write_str("a map with potential duplicate fields")
+                }
+
+                fn visit_map<V>(self, mut map: V) -> Result<#deserialization_target_type_identifier, V::Error>
+                    where
+                    V: serde::de::MapAccess<'de>,
+                {
+                    let __human_readable = self.human_readable;
+
+                    #warnings_declaration
+
+                    #policy_declaration
+
+                    #policy_propagation
+
+                    #unknown_entries_declaration
+
+                    #key_order_declaration
+
+                    #( #declarations )*
+
+                    while let Some(key) = map.next_key::<::serde_deserialize_duplicates::__private::FieldKey<'de>>()? {
+                        match &*key {
+                            #( #key_value_mapping_patterns, )*
+                            _ => {
+                                #unknown_entry_recording
+
+                                #unknown_entry_handling
+                            }
+                        }
+                    }
+
+                    #( #value_extractions )*
+
+                    #( #metadata_extractions )*
+
+                    Ok(#deserialization_target_type_identifier { #(#deserialization_target_field_identifiers),* })
+                }
+            }
+        };
+    }
+    .into()
+}
+
+/// The variable holding the key of every occurrence found for a field
+fn keys_identifier(identifier: &Ident) -> Ident {
+    format_ident!("__{}_keys", identifier.unraw())
+}
+
+/// The variable holding the index of the occurrence used for a field
+fn selected_identifier(identifier: &Ident) -> Ident {
+    format_ident!("__{}_selected", identifier.unraw())
+}
+
+/// Declare the variable holding the occurrences found for a field
+fn generate_declaration(field: &FieldContext) -> TokenStream {
+    let FieldContext {
+        identifier,
+        field_type,
+        keys_identifier,
+        ..
+    } = field;
+
+    let occurrences_declaration = if field.options.buffers_occurrences() {
+        quote! { let mut #identifier: Vec<(usize, ::serde_deserialize_duplicates::__private::Content<'de>)> = Vec::new(); }
+    } else if field.strategy.collects_occurrences() {
+        quote! { let mut #identifier: Vec<(usize, #field_type)> = Vec::new(); }
+    } else if field.strategy == DuplicateStrategy::Collect {
+        quote! { let mut #identifier: Vec<::serde_deserialize_duplicates::__private::Content<'de>> = Vec::new(); }
+    } else if field.strategy == DuplicateStrategy::Raw {
+        quote! {
+            ::serde_deserialize_duplicates::__require_serde_json_feature!("raw");
+
+            let mut #identifier: Vec<Box<::serde_deserialize_duplicates::__private::RawValue>> = Vec::new();
+        }
+    } else if field.follows_policy {
+        let collected_identifier = &field.collected_identifier;
+
+        quote! {
+            let mut #identifier = None;
+            let mut #collected_identifier: Vec<::serde_deserialize_duplicates::__private::Content<'de>> = Vec::new();
+        }
+    } else {
+        quote! { let mut #identifier = None; }
+    };
+
+    quote! {
+        #occurrences_declaration
+        let mut #keys_identifier: Vec<::serde_deserialize_duplicates::__private::FieldKey<'de>> = Vec::new();
+    }
+}
+
+/// Generate the match arm storing an occurrence of any of a field's names
+fn generate_match_arm(field: &FieldContext) -> TokenStream {
+    let FieldContext {
+        identifier,
+        field_type,
+        keys_identifier,
+        names,
+        ..
+    } = field;
+
+    let next_value = generate_next_value(field);
+
+    let occurrence_storage = if field.options.buffers_occurrences() {
+        quote! {
+            #identifier.push((#keys_identifier.len(), map.next_value::<::serde_deserialize_duplicates::__private::Content<'de>>()?));
+        }
+    } else if field.strategy.collects_occurrences() {
+        quote! {
+            #identifier.push((#keys_identifier.len(), #next_value?));
+        }
+    } else if field.follows_policy {
+        let collected_identifier = &field.collected_identifier;
+
+        quote! {
+            match __policy {
+                ::serde_deserialize_duplicates::DuplicatePolicy::Last => {
+                    #identifier = Some(#next_value?);
+                }
+                ::serde_deserialize_duplicates::DuplicatePolicy::Collect => {
+                    #collected_identifier.push(map.next_value::<::serde_deserialize_duplicates::__private::Content<'de>>()?);
+                }
+                ::serde_deserialize_duplicates::DuplicatePolicy::First
+                | ::serde_deserialize_duplicates::DuplicatePolicy::Error => {
+                    if #identifier.is_none() {
+                        #identifier = Some(#next_value?);
+                    } else {
+                        map.next_value::<serde::de::IgnoredAny>()?;
+                    }
+                }
+            }
+        }
+    } else {
+        match field.strategy {
+            DuplicateStrategy::First => quote! {
+                if #identifier.is_none() {
+                    #identifier = Some(#next_value?);
+                } else {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+                }
+            },
+            // Only the occurrence at the position is deserialized, so the others need not match the field's type
+            DuplicateStrategy::Nth(position) => {
+                let index = position.unsigned_abs() - 1;
+
+                quote! {
+                    if #keys_identifier.len() == #index {
+                        #identifier = Some(#next_value?);
+                    } else {
+                        map.next_value::<serde::de::IgnoredAny>()?;
+                    }
+                }
+            }
+            DuplicateStrategy::Collect => quote! {
+                #identifier.push(map.next_value::<::serde_deserialize_duplicates::__private::Content<'de>>()?);
+            },
+            DuplicateStrategy::Raw => quote! {
+                #identifier.push(map.next_value::<Box<::serde_deserialize_duplicates::__private::RawValue>>()?);
+            },
+            DuplicateStrategy::Extend => quote! {
+                let __occurrence: #field_type = #next_value?;
+
+                match &mut #identifier {
+                    Some(__collection) => Extend::extend(__collection, __occurrence),
+                    None => #identifier = Some(__occurrence),
+                }
+            },
+            _ => quote! {
+                #identifier = Some(#next_value?);
+            },
+        }
+    };
+
+    let key_order_recording = if field.records_key_order {
+        let identifier_display_representation = identifier.to_string();
+
+        quote! {
+            __key_order.push((key.to_string(), Some(#identifier_display_representation), #keys_identifier.len()));
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #( #names )|* => {
+            #key_order_recording
+            #occurrence_storage
+            #keys_identifier.push(key);
+        }
+    }
+}
+
+/// Read the value of the current entry as an occurrence of a field
+fn generate_next_value(field: &FieldContext) -> TokenStream {
+    let field_type = field.field_type;
+
+    match field.options.map_keys {
+        Some(map_keys_policy) => quote! {
+            map.next_value_seed(::serde_deserialize_duplicates::__private::MapKeys::<#field_type>::new(#map_keys_policy))
+        },
+        None => quote! { map.next_value::<#field_type>() },
+    }
+}
+
+/// Deserialize a field's value from a deserializer of buffered content, honouring map_keys
+fn generate_content_deserialization(field: &FieldContext, deserializer: TokenStream) -> TokenStream {
+    let field_type = field.field_type;
+
+    match field.options.map_keys {
+        Some(map_keys_policy) => quote! {
+            serde::de::DeserializeSeed::deserialize(
+                ::serde_deserialize_duplicates::__private::MapKeys::<#field_type>::new(#map_keys_policy),
+                #deserializer,
+            )
+        },
+        None => quote! { <#field_type as serde::Deserialize>::deserialize(#deserializer) },
+    }
+}
+
+/// Produce the final value of a field from the occurrences found for it
+fn generate_value_extraction(field: &FieldContext) -> TokenStream {
+    let FieldContext {
+        identifier,
+        field_type,
+        keys_identifier,
+        selected_identifier,
+        type_name,
+        ..
+    } = field;
+
+    let identifier_display_representation = identifier.to_string();
+
+    let occurrence_selection = if field.options.buffers_occurrences() {
+        generate_occurrence_selection(field)
+    } else if field.follows_policy {
+        let collected_identifier = &field.collected_identifier;
+
+        let deserialize_collected = generate_content_deserialization(field, quote! { __collected });
+
+        quote! {
+            let #identifier = match __policy {
+                ::serde_deserialize_duplicates::DuplicatePolicy::Collect if #collected_identifier.len() == 1 => {
+                    Some(::serde_deserialize_duplicates::__private::deserialize_single_occurrence(
+                        #collected_identifier.remove(0),
+                        __human_readable,
+                        |__collected| #deserialize_collected,
+                    )?)
+                }
+                ::serde_deserialize_duplicates::DuplicatePolicy::Collect if !#collected_identifier.is_empty() => {
+                    let __collected = ::serde_deserialize_duplicates::__private::ContentDeserializer::<V::Error>::preserving(
+                        ::serde_deserialize_duplicates::__private::Content::Seq(#collected_identifier),
+                    )
+                    .human_readable(__human_readable);
+
+                    Some(#deserialize_collected?)
+                }
+                _ => #identifier,
+            };
+
+            let #selected_identifier = match __policy {
+                ::serde_deserialize_duplicates::DuplicatePolicy::Last => #keys_identifier.len().checked_sub(1),
+                _ => (!#keys_identifier.is_empty()).then_some(0),
+            };
+        }
+    } else if field.strategy.collects_occurrences() {
+        let value_reduction = generate_value_reduction(field, identifier);
+
+        quote! {
+            let (#identifier, #selected_identifier): (Option<#field_type>, Option<usize>) = #value_reduction;
+        }
+    } else {
+        match field.strategy {
+            DuplicateStrategy::First => quote! {
+                let #selected_identifier = (!#keys_identifier.is_empty()).then_some(0);
+            },
+            DuplicateStrategy::Last => quote! {
+                let #selected_identifier = #keys_identifier.len().checked_sub(1);
+            },
+            DuplicateStrategy::Nth(position) => {
+                let index = position.unsigned_abs() - 1;
+
+                quote! {
+                    let #selected_identifier = #identifier.is_some().then_some(#index);
+                }
+            }
+            // Even a single occurrence is deserialized as a sequence, so the field always holds one element per occurrence
+            DuplicateStrategy::Collect => quote! {
+                let #identifier: Option<#field_type> = if #identifier.is_empty() {
+                    None
+                } else {
+                    Some(serde::Deserialize::deserialize(
+                        ::serde_deserialize_duplicates::__private::ContentDeserializer::<V::Error>::preserving(
+                            ::serde_deserialize_duplicates::__private::Content::Seq(#identifier),
+                        )
+                        .human_readable(__human_readable),
+                    )?)
+                };
+
+                let #selected_identifier: Option<usize> = None;
+            },
+            DuplicateStrategy::Raw => quote! {
+                let #identifier: Option<#field_type> = if #identifier.is_empty() {
+                    None
+                } else {
+                    Some(#identifier.into_iter().collect())
+                };
+
+                let #selected_identifier: Option<usize> = None;
+            },
+            _ => quote! {
+                let #selected_identifier: Option<usize> = None;
+            },
+        }
+    };
+
+    let value_extractor = if field.use_default {
+        quote! {unwrap_or_default()}
+    } else {
+        quote! {ok_or_else(|| serde::de::Error::missing_field(#identifier_display_representation))?}
+    };
+
+    let discarded_occurrences_notification = if field.strategy.discards_occurrences() {
+        // Collected occurrences are combined rather than discarded
+        let discarded_candidates = if field.follows_policy {
+            quote! {
+                #keys_identifier
+                    .iter()
+                    .enumerate()
+                    .filter(|_| !matches!(__policy, ::serde_deserialize_duplicates::DuplicatePolicy::Collect))
+            }
+        } else {
+            quote! { #keys_identifier.iter().enumerate() }
+        };
+
+        let tracks_occurrence_outcomes = field.options.buffers_occurrences();
+
+        // Occurrences skipped as invalid were reported as such already
+        let not_yet_reported = if tracks_occurrence_outcomes && field.options.skip_invalid {
+            let skipped_identifier = &field.skipped_identifier;
+
+            quote! { && !#skipped_identifier.contains(&__index) }
+        } else {
+            quote! {}
+        };
+
+        let discarded_occurrences_loop = quote! {
+            for (__index, __key) in #discarded_candidates {
+                if Some(__index) != #selected_identifier #not_yet_reported {
+                    ::serde_deserialize_duplicates::__private::discard_duplicate(
+                        #type_name,
+                        #identifier_display_representation,
+                        __key,
+                        __index,
+                    );
+                }
+            }
+        };
+
+        // When the default was used no occurrence was selected over the others
+        if tracks_occurrence_outcomes && field.options.default_on_error {
+            let defaulted_identifier = &field.defaulted_identifier;
+
+            quote! {
+                if !#defaulted_identifier {
+                    #discarded_occurrences_loop
+                }
+            }
+        } else {
+            discarded_occurrences_loop
+        }
+    } else {
+        quote! {}
+    };
+
+    let policy_handling = if field.follows_policy {
+        quote! {
+            let #selected_identifier = match __policy {
+                ::serde_deserialize_duplicates::DuplicatePolicy::Error if #keys_identifier.len() > 1 => {
+                    return Err(serde::de::Error::duplicate_field(#identifier_display_representation));
+                }
+                ::serde_deserialize_duplicates::DuplicatePolicy::Collect if #keys_identifier.len() > 1 => None,
+                _ => #selected_identifier,
+            };
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #occurrence_selection
+
+        #policy_handling
+
+        let #identifier = #identifier.#value_extractor;
+
+        if #keys_identifier.len() > 1 {
+            ::serde_deserialize_duplicates::__private::record_duplicate(
+                #type_name,
+                #identifier_display_representation,
+                &#keys_identifier,
+                #selected_identifier,
+            );
+
+            #discarded_occurrences_notification
+        }
+    }
+}
+
+/// Produce the value of a field populated with information about the other fields
+fn generate_metadata_extraction(identifier: &Ident, options: &DuplicateOptions, fields: &[FieldContext]) -> TokenStream {
+    if options.key_order {
+        let field_names = fields.iter().map(|field| field.identifier.to_string());
+        let selected_identifiers = fields.iter().map(|field| &field.selected_identifier);
+
+        return quote! {
+            let #identifier = ::serde_deserialize_duplicates::KeyOrder {
+                entries: __key_order
+                    .iter()
+                    .map(|(__key, __field, __occurrence)| ::serde_deserialize_duplicates::KeyOrderEntry {
+                        key: __key.clone(),
+                        field: *__field,
+                        selected: match *__field {
+                            #( Some(#field_names) => #selected_identifiers == Some(*__occurrence), )*
+                            _ => false,
+                        },
+                    })
+                    .collect(),
+            };
+        };
+    }
+
+    if let Some(source_field) = &options.source_of {
+        let keys_identifier = keys_identifier(source_field);
+        let selected_identifier = selected_identifier(source_field);
+
+        return quote! {
+            let #identifier = ::serde_deserialize_duplicates::__private::SourceKey::from_source_key(
+                #selected_identifier.map(|__index| &*#keys_identifier[__index]),
+            );
+        };
+    }
+
+    if let Some(counted_field) = &options.count_of {
+        let keys_identifier = keys_identifier(counted_field);
+
+        return quote! {
+            let #identifier = #keys_identifier.len().try_into().map_err(serde::de::Error::custom)?;
+        };
+    }
+
+    if options.captures_unknown_keys() {
+        let unknown_entries = if options.extra {
+            quote! { ::serde_deserialize_duplicates::__private::deduplicate(__unknown_entries, __policy)? }
+        } else {
+            quote! { __unknown_entries }
+        };
+
+        return quote! {
+            let #identifier = serde::Deserialize::deserialize(
+                ::serde_deserialize_duplicates::__private::ContentDeserializer::<V::Error>::preserving(
+                    ::serde_deserialize_duplicates::__private::Content::Map(#unknown_entries),
+                )
+                .human_readable(__human_readable),
+            )?;
+        };
+    }
+
+    quote! {
+        let #identifier = __duplicate_warnings.iter().cloned().collect();
+    }
+}
+
+/// Reduce the buffered occurrences of a field down to a single value
+fn generate_occurrence_selection(field: &FieldContext) -> TokenStream {
+    let FieldContext {
+        identifier,
+        field_type,
+        selected_identifier,
+        keys_identifier,
+        skipped_identifier,
+        defaulted_identifier,
+        options,
+        strategy,
+        reports_warnings,
+        type_name,
+        ..
+    } = field;
+
+    // Only strategies discarding occurrences report the rest, so only they need to know which were reported already
+    let tracks_skipped_occurrences = options.skip_invalid && strategy.discards_occurrences();
+
+    let tracks_default_use = options.default_on_error && strategy.discards_occurrences();
+
+    let identifier_display_representation = identifier.to_string();
+
+    let ordered_occurrences = match strategy {
+        _ if field.follows_policy => quote! {
+            {
+                let mut __occurrences = #identifier;
+
+                match __policy {
+                    ::serde_deserialize_duplicates::DuplicatePolicy::Last => __occurrences.reverse(),
+                    // Every occurrence is deserialized together, under the index of the first
+                    ::serde_deserialize_duplicates::DuplicatePolicy::Collect if __occurrences.len() > 1 => {
+                        let __index = __occurrences[0].0;
+                        let __collected = __occurrences.drain(..).map(|(_, __occurrence)| __occurrence).collect();
+
+                        __occurrences.push((__index, ::serde_deserialize_duplicates::__private::Content::Seq(__collected)));
+                    }
+                    _ => {}
+                }
+
+                __occurrences.into_iter()
+            }
+        },
+        DuplicateStrategy::Last => quote! { #identifier.into_iter().rev() },
+        // With skip_invalid the position counts valid occurrences only, so it is selected once they are deserialized
+        DuplicateStrategy::Nth(position) if !options.skip_invalid => {
+            let nth_selection = generate_nth_selection(identifier, *position);
+
+            quote! { #nth_selection.into_iter() }
+        }
+        DuplicateStrategy::Merge => quote! {
+            #identifier
+                .into_iter()
+                .reduce(|(_, mut __merged), (__index, __occurrence)| {
+                    ::serde_deserialize_duplicates::__private::merge(&mut __merged, __occurrence);
+                    (__index, __merged)
+                })
+                .into_iter()
+        },
+        _ => quote! { #identifier.into_iter() },
+    };
+
+    let selects_single_occurrence = strategy.selects_single_occurrence();
+
+    let selects_among_valid_occurrences = matches!(strategy, DuplicateStrategy::Nth(_)) && options.skip_invalid;
+
+    // Without skip_invalid only the selected occurrence is considered, so errors are not fallen through
+    let candidate_occurrences = if selects_single_occurrence && !options.skip_invalid {
+        quote! { #ordered_occurrences.take(1) }
+    } else {
+        ordered_occurrences
+    };
+
+    let stop_after_valid_occurrence = if selects_single_occurrence && !selects_among_valid_occurrences {
+        quote! { break; }
+    } else {
+        quote! {}
+    };
+
+    let record_skipped_occurrence = if *reports_warnings && options.skip_invalid {
+        quote! {
+            __duplicate_warnings.push(format!(
+                "skipped invalid occurrence of field `{}`: {}",
+                #identifier_display_representation,
+                __error
+            ));
+        }
+    } else {
+        quote! {}
+    };
+
+    let record_skipped_index = if tracks_skipped_occurrences {
+        quote! { #skipped_identifier.push(__index); }
+    } else {
+        quote! {}
+    };
+
+    let notify_skipped_occurrence = if options.skip_invalid {
+        quote! {
+            ::serde_deserialize_duplicates::__private::skip_invalid_value(
+                #type_name,
+                #identifier_display_representation,
+                &#keys_identifier[__index],
+                __index,
+                &__error,
+            );
+
+            #record_skipped_index
+        }
+    } else {
+        quote! {}
+    };
+
+    let has_failed = if options.skip_invalid {
+        quote! { __values.is_empty() && !__errors.is_empty() }
+    } else {
+        quote! { !__errors.is_empty() }
+    };
+
+    let invalid_value_handling = if options.default_on_error {
+        let record_default_used = if *reports_warnings {
+            quote! {
+                __duplicate_warnings.push(format!(
+                    "used default value for field `{}`: {}",
+                    #identifier_display_representation,
+                    __errors.join("; ")
+                ));
+            }
+        } else {
+            quote! {}
+        };
+
+        let record_default_use = if tracks_default_use {
+            quote! { #defaulted_identifier = true; }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            #record_default_used
+
+            #record_default_use
+
+            ::serde_deserialize_duplicates::__private::use_default(
+                #type_name,
+                #identifier_display_representation,
+                &__errors,
+            );
+
+            (Some(Default::default()), None)
+        }
+    } else {
+        quote! {
+            return Err(serde::de::Error::custom(format!(
+                "no occurrence of field `{}` could be deserialized: {}",
+                #identifier_display_representation,
+                __errors.join("; ")
+            )))
+        }
+    };
+
+    let deserialize_occurrence = generate_content_deserialization(field, quote! { __occurrence });
+
+    // A lone occurrence collected under a runtime policy may stand for a sequence of one
+    let occurrence_deserialization = if field.follows_policy {
+        quote! {
+            if __single_collected {
+                ::serde_deserialize_duplicates::__private::deserialize_single_occurrence(
+                    __occurrence,
+                    __human_readable,
+                    |__occurrence| #deserialize_occurrence,
+                )
+            } else {
+                let __occurrence = ::serde_deserialize_duplicates::__private::ContentDeserializer::<V::Error>::preserving(__occurrence)
+                    .human_readable(__human_readable);
+
+                #deserialize_occurrence
+            }
+        }
+    } else {
+        quote! {
+            {
+                let __occurrence = ::serde_deserialize_duplicates::__private::ContentDeserializer::<V::Error>::preserving(__occurrence)
+                    .human_readable(__human_readable);
+
+                #deserialize_occurrence
+            }
+        }
+    };
+
+    let single_collected_declaration = if field.follows_policy {
+        quote! {
+            let __single_collected =
+                matches!(__policy, ::serde_deserialize_duplicates::DuplicatePolicy::Collect) && #identifier.len() == 1;
+        }
+    } else {
+        quote! {}
+    };
+
+    // Single occurrence strategies have already made their selection among the candidates
+    let value_reduction = if *strategy == DuplicateStrategy::Merge {
+        quote! { (__values.into_iter().next().map(|(_, __value)| __value), None) }
+    } else if selects_single_occurrence && !selects_among_valid_occurrences {
+        generate_selection_split(quote! { __values.into_iter().next() })
+    } else {
+        generate_value_reduction(field, &Ident::new("__values", Span::call_site()))
+    };
+
+    let skipped_declaration = if tracks_skipped_occurrences {
+        quote! { let mut #skipped_identifier: Vec<usize> = Vec::new(); }
+    } else {
+        quote! {}
+    };
+
+    let defaulted_declaration = if tracks_default_use {
+        quote! { let mut #defaulted_identifier = false; }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #skipped_declaration
+        #defaulted_declaration
+
+        let (#identifier, #selected_identifier): (Option<#field_type>, Option<usize>) = {
+            let mut __values: Vec<(usize, #field_type)> = Vec::new();
+            let mut __errors = Vec::new();
+
+            #single_collected_declaration
+
+            for (__index, __occurrence) in #candidate_occurrences {
+                match #occurrence_deserialization {
+                    Ok(__value) => {
+                        __values.push((__index, __value));
+                        #stop_after_valid_occurrence
+                    }
+                    Err(__error) => {
+                        #record_skipped_occurrence
+
+                        #notify_skipped_occurrence
+
+                        __errors.push(__error.to_string());
+                    }
+                }
+            }
+
+            if #has_failed {
+                #invalid_value_handling
+            } else {
+                #value_reduction
+            }
+        };
+    }
+}
+
+/// Reduce a list of deserialized occurrences, each paired with its index, into an optional value and the index of the occurrence used
+fn generate_value_reduction(field: &FieldContext, values_identifier: &Ident) -> TokenStream {
+    let combined_value = match &field.strategy {
+        DuplicateStrategy::Nth(position) => {
+            return generate_selection_split(generate_nth_selection(values_identifier, *position));
+        }
+        DuplicateStrategy::Extend => quote! {
+            #values_identifier.into_iter().map(|(_, __value)| __value).reduce(|mut __collection, __value| {
+                Extend::extend(&mut __collection, __value);
+                __collection
+            })
+        },
+        DuplicateStrategy::Resolve(resolver) => {
+            let keys_identifier = &field.keys_identifier;
+
+            quote! {
+                if #values_identifier.is_empty() {
+                    None
+                } else {
+                    let __occurrences = #values_identifier
+                        .into_iter()
+                        .map(|(__index, __value)| (&*#keys_identifier[__index], __value))
+                        .collect();
+
+                    Some(#resolver(__occurrences).map_err(serde::de::Error::custom)?)
+                }
+            }
+        }
+        DuplicateStrategy::Sum => {
+            let overflow_message = format!("sum of field `{}` overflowed", field.identifier);
+
+            let field_type = field.field_type;
+
+            quote! {
+                {
+                    #[allow(unused_imports)]
+                    use ::serde_deserialize_duplicates::__private::{CheckedSummation as _, UncheckedSummation as _};
+
+                    // Overflow is only detected for types implementing CheckedAdd, others are added as they define
+                    let __add = (&::serde_deserialize_duplicates::__private::Summation::<#field_type>::new()).adder();
+
+                    let mut __total = None;
+
+                    for (_, __value) in #values_identifier {
+                        __total = Some(match __total {
+                            Some(__total) => __add(__total, __value).ok_or_else(|| serde::de::Error::custom(#overflow_message))?,
+                            None => __value,
+                        });
+                    }
+
+                    __total
+                }
+            }
+        }
+        DuplicateStrategy::Min => quote! {
+            #values_identifier.into_iter().map(|(_, __value)| __value).reduce(|__smallest, __value| {
+                if __value < __smallest { __value } else { __smallest }
+            })
+        },
+        DuplicateStrategy::Max => quote! {
+            #values_identifier.into_iter().map(|(_, __value)| __value).reduce(|__largest, __value| {
+                if __value > __largest { __value } else { __largest }
+            })
+        },
+        DuplicateStrategy::Majority(tie_breaker) => {
+            let identifier_display_representation = field.identifier.to_string();
+
+            let tie_breaking = match tie_breaker {
+                TieBreaker::First => quote! { __tied.next() },
+                TieBreaker::Last => quote! { __tied.max_by_key(|(_, _, __last_seen)| *__last_seen) },
+                TieBreaker::Error => quote! {
+                    match (__tied.next(), __tied.next()) {
+                        (Some(_), Some(_)) => {
+                            return Err(serde::de::Error::custom(format!(
+                                "no single most frequent occurrence of field `{}`",
+                                #identifier_display_representation
+                            )))
+                        }
+                        (__winner, _) => __winner,
+                    }
+                },
+            };
+
+            quote! {
+                {
+                    // Each distinct value with the number of times it was seen and the index it was last seen at
+                    let mut __tallies: Vec<(_, usize, usize)> = Vec::new();
+
+                    for (__index, (_, __value)) in #values_identifier.into_iter().enumerate() {
+                        match __tallies.iter_mut().find(|(__tallied, _, _)| *__tallied == __value) {
+                            Some((_, __count, __last_seen)) => {
+                                *__count += 1;
+                                *__last_seen = __index;
+                            }
+                            None => __tallies.push((__value, 1, __index)),
+                        }
+                    }
+
+                    let __highest_count = __tallies.iter().map(|(_, __count, _)| *__count).max();
+
+                    #[allow(unused_mut)]
+                    let mut __tied = __tallies
+                        .into_iter()
+                        .filter(|(_, __count, _)| Some(*__count) == __highest_count);
+
+                    let __winner = #tie_breaking;
+
+                    __winner.map(|(__value, _, _)| __value)
+                }
+            }
+        }
+        _ => return generate_selection_split(quote! { #values_identifier.into_iter().next() }),
+    };
+
+    quote! { (#combined_value, None) }
+}
+
+/// Split an optional occurrence paired with its index into the optional value and the optional index
+fn generate_selection_split(selected_occurrence: TokenStream) -> TokenStream {
+    quote! {
+        match #selected_occurrence {
+            Some((__index, __value)) => (Some(__value), Some(__index)),
+            None => (None, None),
+        }
+    }
+}
+
+/// Select the occurrence at a one-based position of a list of occurrences, counting back from the end when negative
+fn generate_nth_selection(occurrences_identifier: &Ident, position: isize) -> TokenStream {
+    let index = position.unsigned_abs() - 1;
+
+    if position > 0 {
+        quote! { #occurrences_identifier.into_iter().nth(#index) }
+    } else {
+        quote! { #occurrences_identifier.into_iter().rev().nth(#index) }
+    }
+}
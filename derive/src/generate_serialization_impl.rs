@@ -0,0 +1,258 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, LitStr};
+
+use crate::aliased_field::{AliasedFields, DuplicateOptions};
+use crate::duplicate_strategy::DuplicateStrategy;
+
+/// How the entries of a single field are written
+enum FieldEntries<'a> {
+    /// A single entry holding the field's value
+    Value { name: &'a LitStr },
+
+    /// An entry for each occurrence the field holds
+    Occurrences { name: &'a LitStr },
+
+    /// Every entry whose key matched no other field, exactly as found
+    Rest,
+
+    /// Every entry whose key matched no other field, after the struct's duplicate policy was applied
+    Extra,
+}
+
+/// The parts of a single field needed to generate its serialization
+struct FieldContext<'a> {
+    /// The original field's identifier
+    identifier: &'a Ident,
+
+    /// How the field's entries are written
+    entries: FieldEntries<'a>,
+
+    /// The variable tracking which of the field's entries remain to be written, either whether the field was written
+    /// or an iterator over the entries still to be written
+    progress_identifier: Ident,
+}
+
+pub fn generate_serialization_impl(
+    serialization_target_type_identifier: Ident,
+    aliased_fields: AliasedFields,
+) -> proc_macro::TokenStream {
+    let AliasedFields {
+        field_identifiers,
+        serialized_names,
+        options_list,
+        ..
+    } = aliased_fields;
+
+    let fields = field_identifiers
+        .iter()
+        .zip(&serialized_names)
+        .zip(&options_list)
+        .filter_map(|((identifier, serialized_name), options)| {
+            Some(FieldContext {
+                identifier,
+                entries: field_entries(serialized_name, options)?,
+                progress_identifier: format_ident!("__{}_progress", identifier.unraw()),
+            })
+        })
+        .collect::<Vec<FieldContext>>();
+
+    let key_order_identifier = field_identifiers
+        .iter()
+        .zip(&options_list)
+        .find(|(_, options)| options.key_order)
+        .map(|(identifier, _)| identifier);
+
+    let progress_declarations = fields.iter().map(generate_progress_declaration).collect::<Vec<TokenStream>>();
+
+    let ordered_entry_serialization = match key_order_identifier {
+        Some(key_order_identifier) => {
+            let ordered_serialization_arms = fields
+                .iter()
+                .filter_map(|field| generate_ordered_serialization_arm(field, key_order_identifier))
+                .collect::<Vec<TokenStream>>();
+
+            let unknown_entry_serialization = fields
+                .iter()
+                .filter_map(generate_unknown_entry_serialization)
+                .collect::<Vec<TokenStream>>();
+
+            quote! {
+                for (__position, __entry) in self.#key_order_identifier.iter().enumerate() {
+                    match __entry.field {
+                        #( #ordered_serialization_arms )*
+                        None => {
+                            #( #unknown_entry_serialization )*
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let remaining_entry_serializations = fields
+        .iter()
+        .map(generate_remaining_entry_serialization)
+        .collect::<Vec<TokenStream>>();
+
+    quote! {
+        const _: () = {
+            impl serde::Serialize for #serialization_target_type_identifier {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    let mut map = serde::Serializer::serialize_map(serializer, None)?;
+
+                    #( #progress_declarations )*
+
+                    // Entries are first written in the order they were found, if it was recorded
+                    #ordered_entry_serialization
+
+                    #( #remaining_entry_serializations )*
+
+                    serde::ser::SerializeMap::end(map)
+                }
+            }
+        };
+    }
+    .into()
+}
+
+/// How a field's entries are written, or [None] if it describes the input rather than being part of it
+fn field_entries<'a>(serialized_name: &'a LitStr, options: &DuplicateOptions) -> Option<FieldEntries<'a>> {
+    if options.rest {
+        return Some(FieldEntries::Rest);
+    }
+
+    if options.extra {
+        return Some(FieldEntries::Extra);
+    }
+
+    if options.is_metadata() {
+        return None;
+    }
+
+    if options.strategy.as_ref().is_some_and(DuplicateStrategy::keeps_every_occurrence) {
+        return Some(FieldEntries::Occurrences { name: serialized_name });
+    }
+
+    Some(FieldEntries::Value { name: serialized_name })
+}
+
+/// Declare the variable tracking which of a field's entries remain to be written
+fn generate_progress_declaration(field: &FieldContext) -> TokenStream {
+    let FieldContext {
+        identifier,
+        progress_identifier,
+        ..
+    } = field;
+
+    match field.entries {
+        FieldEntries::Value { .. } | FieldEntries::Extra => quote! {
+            #[allow(unused_mut)]
+            let mut #progress_identifier = false;
+        },
+        FieldEntries::Occurrences { .. } | FieldEntries::Rest => quote! {
+            #[allow(unused_mut)]
+            let mut #progress_identifier = (&self.#identifier).into_iter();
+        },
+    }
+}
+
+/// Generate the match arm writing an entry recorded as an occurrence of a field, found in the given key order
+fn generate_ordered_serialization_arm(field: &FieldContext, key_order_identifier: &Ident) -> Option<TokenStream> {
+    let FieldContext {
+        identifier,
+        progress_identifier,
+        ..
+    } = field;
+
+    let identifier_display_representation = identifier.to_string();
+
+    match field.entries {
+        // The value is written once, under the key of the occurrence it was taken from
+        FieldEntries::Value { .. } => Some(quote! {
+            Some(#identifier_display_representation) => {
+                if !#progress_identifier
+                    && self.#key_order_identifier.value_position(#identifier_display_representation) == Some(__position)
+                {
+                    serde::ser::SerializeMap::serialize_entry(&mut map, &__entry.key, &self.#identifier)?;
+                    #progress_identifier = true;
+                }
+            }
+        }),
+        FieldEntries::Occurrences { .. } => Some(quote! {
+            Some(#identifier_display_representation) => {
+                if let Some(__occurrence) = #progress_identifier.next() {
+                    serde::ser::SerializeMap::serialize_entry(&mut map, &__entry.key, __occurrence)?;
+                }
+            }
+        }),
+        FieldEntries::Rest | FieldEntries::Extra => None,
+    }
+}
+
+/// Write the next entry of a field capturing unknown keys in place of an entry whose key matched no field
+fn generate_unknown_entry_serialization(field: &FieldContext) -> Option<TokenStream> {
+    let FieldContext {
+        identifier,
+        progress_identifier,
+        ..
+    } = field;
+
+    match field.entries {
+        FieldEntries::Rest => Some(quote! {
+            if let Some((__key, __value)) = #progress_identifier.next() {
+                serde::ser::SerializeMap::serialize_entry(&mut map, __key, __value)?;
+            }
+        }),
+        // Entries reduced by a policy no longer line up with those found, so they are written together at the first
+        FieldEntries::Extra => Some(quote! {
+            if !#progress_identifier {
+                for (__key, __value) in &self.#identifier {
+                    serde::ser::SerializeMap::serialize_entry(&mut map, __key, __value)?;
+                }
+
+                #progress_identifier = true;
+            }
+        }),
+        FieldEntries::Value { .. } | FieldEntries::Occurrences { .. } => None,
+    }
+}
+
+/// Write the entries of a field which were not written in the order they were found
+fn generate_remaining_entry_serialization(field: &FieldContext) -> TokenStream {
+    let FieldContext {
+        identifier,
+        progress_identifier,
+        ..
+    } = field;
+
+    match field.entries {
+        FieldEntries::Value { name } => quote! {
+            if !#progress_identifier {
+                serde::ser::SerializeMap::serialize_entry(&mut map, #name, &self.#identifier)?;
+            }
+        },
+        FieldEntries::Occurrences { name } => quote! {
+            for __occurrence in #progress_identifier {
+                serde::ser::SerializeMap::serialize_entry(&mut map, #name, __occurrence)?;
+            }
+        },
+        FieldEntries::Rest => quote! {
+            for (__key, __value) in #progress_identifier {
+                serde::ser::SerializeMap::serialize_entry(&mut map, __key, __value)?;
+            }
+        },
+        FieldEntries::Extra => quote! {
+            if !#progress_identifier {
+                for (__key, __value) in &self.#identifier {
+                    serde::ser::SerializeMap::serialize_entry(&mut map, __key, __value)?;
+                }
+            }
+        },
+    }
+}
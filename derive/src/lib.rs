@@ -0,0 +1,179 @@
+//! # Serde Deserialize Duplicates Derive Macros
+//!
+//! Derive macros for the [serde-deserialize-duplicates](https://docs.rs/serde-deserialize-duplicates) crate.
+//! The code they generate relies on that crate, so depend on it rather than on this one directly.
+
+#![warn(missing_docs)]
+
+use container_options::ContainerOptions;
+use duplicate_strategy::DuplicateStrategy;
+use quote::quote_spanned;
+use syn::{parse_macro_input, DeriveInput};
+
+mod aliased_field;
+mod container_options;
+mod duplicate_strategy;
+mod generate_deserialization_impl;
+mod generate_serialization_impl;
+mod parse_fields;
+mod policy;
+
+use generate_deserialization_impl::generate_deserialization_impl;
+use generate_serialization_impl::generate_serialization_impl;
+use parse_fields::parse_fields;
+
+/// # Deserialize First Duplicate macro
+///
+/// Macro which creates a deserializer for a struct with named values which takes the first matching value it finds, ignoring the rest.
+///
+/// ## Usage
+///
+///```rust
+///use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+///use serde::Serialize;
+///
+///// Target struct
+///#[derive(Serialize, DeserializeFirstDuplicate)]
+///struct ValueHolder {
+///    pub value: String
+///}
+///
+///// Mock data with duplicate values
+///let data = r#"
+///     {
+///         "value": "first",
+///         "value": "second"
+///     }"#;
+///
+///// Deserialize our data into a ValueHolder, taking the first value we find
+///let holder: ValueHolder = serde_json::from_str(data).unwrap();
+///
+///assert_eq!(holder.value, "first".to_owned());
+///```
+#[proc_macro_derive(DeserializeFirstDuplicate, attributes(duplicates))]
+pub fn deserialize_first_duplicate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let parsed_input = parse_fields(input.data).and_then(|aliased_fields| {
+        Ok((aliased_fields, ContainerOptions::try_from(input.attrs.as_slice())?))
+    });
+
+    match parsed_input {
+        Ok((aliased_fields, container_options)) => generate_deserialization_impl(
+            input.ident,
+            aliased_fields,
+            container_options,
+            DuplicateStrategy::First,
+        ),
+        Err(e) => {
+            let emitted_error = e.to_string();
+
+            quote_spanned! {
+                input.ident.span() =>
+                compile_error!(#emitted_error);
+            }.into()
+        }
+    }
+}
+
+/// # Deserialize Last Duplicate macro
+///
+/// Macro which creates a deserializer for a struct with named values which takes the last matching value it finds.
+///
+/// ## Usage
+///
+///```rust
+///use serde_deserialize_duplicates::DeserializeLastDuplicate;
+///use serde::Serialize;
+///
+///// Target struct
+///#[derive(Serialize, DeserializeLastDuplicate)]
+///struct ValueHolder {
+///    pub value: String
+///}
+///
+///// Mock data with duplicate values
+///let data = r#"
+///     {
+///         "value": "first",
+///         "value": "second"
+///     }"#;
+///
+///// Deserialize our data into a ValueHolder, taking the first value we find
+///let holder: ValueHolder = serde_json::from_str(data).unwrap();
+///
+///assert_eq!(holder.value, "second".to_owned());
+///```
+#[proc_macro_derive(DeserializeLastDuplicate, attributes(duplicates))]
+pub fn deserialize_last_duplicate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let parsed_input = parse_fields(input.data).and_then(|aliased_fields| {
+        Ok((aliased_fields, ContainerOptions::try_from(input.attrs.as_slice())?))
+    });
+
+    match parsed_input {
+        Ok((aliased_fields, container_options)) => generate_deserialization_impl(
+            input.ident,
+            aliased_fields,
+            container_options,
+            DuplicateStrategy::Last,
+        ),
+        Err(e) => {
+            let emitted_error = e.to_string();
+
+            quote_spanned! {
+                input.ident.span() =>
+                compile_error!(#emitted_error);
+            }.into()
+        }
+    }
+}
+
+/// # Serialize Duplicates macro
+///
+/// Macro which creates a serializer for a struct with named values which reproduces the duplicate keys it was deserialized from:
+/// every occurrence of a field annotated with `#[duplicates(collect)]` is written under the field's name, and every entry of a
+/// field annotated with `#[duplicates(rest)]` or `#[duplicates(extra)]` is written next to the struct's own fields. When the
+/// struct has a field annotated with `#[duplicates(key_order)]`, entries are written under the keys, and in the order, it
+/// recorded.
+///
+/// ## Usage
+///
+///```rust
+///use serde_deserialize_duplicates::{DeserializeFirstDuplicate, SerializeDuplicates};
+///
+///// Target struct
+///#[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+///struct ValueHolder {
+///    #[duplicates(collect)]
+///    pub value: Vec<String>
+///}
+///
+///// Mock data with duplicate values
+///let data = r#"{"value":"first","value":"second"}"#;
+///
+///// Deserialize our data into a ValueHolder, keeping every value we find
+///let holder: ValueHolder = serde_json::from_str(data).unwrap();
+///
+///assert_eq!(holder.value, vec!["first".to_owned(), "second".to_owned()]);
+///
+///// Serialize it again, repeating the key for each value
+///assert_eq!(serde_json::to_string(&holder).unwrap(), data);
+///```
+#[proc_macro_derive(SerializeDuplicates, attributes(duplicates, serde))]
+pub fn serialize_duplicates(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match parse_fields(input.data) {
+        Ok(aliased_fields) => generate_serialization_impl(input.ident, aliased_fields),
+        Err(e) => {
+            let emitted_error = e.to_string();
+
+            quote_spanned! {
+                input.ident.span() =>
+                compile_error!(#emitted_error);
+            }.into()
+        }
+    }
+}
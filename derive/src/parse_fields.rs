@@ -0,0 +1,32 @@
+use syn::Data;
+
+use crate::aliased_field::{AliasedField, AliasedFieldError, AliasedFields};
+
+/// Parse [AliasedFields] from [Data]
+pub fn parse_fields(input: Data) -> Result<AliasedFields, AliasedFieldError> {
+    let Data::Struct(struct_data) = input else {
+        panic!("This macro can only be used on structs")
+    };
+
+    let fields = struct_data
+        .fields
+        .into_iter()
+        .map(AliasedField::try_from)
+        .collect::<Result<Vec<_>, AliasedFieldError>>()?;
+
+    // Fields describing other fields must refer to one which is read from the data
+    for referenced_field in fields.iter().filter_map(|field| field.options.referenced_field()) {
+        if !fields
+            .iter()
+            .any(|field| field.field_identifier == *referenced_field && !field.options.is_metadata())
+        {
+            return Err(AliasedFieldError::UnknownField(referenced_field.to_string()));
+        }
+    }
+
+    if fields.iter().filter(|field| field.options.captures_unknown_keys()).count() > 1 {
+        return Err(AliasedFieldError::MultipleUnknownKeyFields);
+    }
+
+    Ok(fields.into())
+}
@@ -0,0 +1,51 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::LitStr;
+
+/// # Policy
+///
+/// A duplicate policy named in an attribute, mirroring the runtime crate's `DuplicatePolicy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Use the first occurrence
+    First,
+
+    /// Use the last occurrence
+    Last,
+
+    /// Fail when anything occurs more than once
+    Error,
+
+    /// Deserialize anything occurring more than once from a sequence of every occurrence
+    Collect,
+}
+
+impl TryFrom<&LitStr> for Policy {
+    type Error = syn::Error;
+
+    fn try_from(value: &LitStr) -> syn::Result<Self> {
+        match value.value().as_str() {
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            "error" => Ok(Self::Error),
+            "collect" => Ok(Self::Collect),
+            _ => Err(syn::Error::new(
+                value.span(),
+                "Expected a policy of \"first\", \"last\", \"error\" or \"collect\"",
+            )),
+        }
+    }
+}
+
+impl ToTokens for Policy {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            Self::First => quote! { First },
+            Self::Last => quote! { Last },
+            Self::Error => quote! { Error },
+            Self::Collect => quote! { Collect },
+        };
+
+        tokens.extend(quote! { ::serde_deserialize_duplicates::DuplicatePolicy::#variant });
+    }
+}
@@ -0,0 +1,182 @@
+//! # dedup-json
+//!
+//! Read JSON documents containing duplicate keys, report every repeated key and write them back out normalised under a
+//! duplicate policy.
+
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+use serde_deserialize_duplicates::{find_duplicate_keys, DuplicatePolicy, DuplicateValue};
+
+const USAGE: &str = "\
+Usage: dedup-json [OPTIONS] [FILE]...
+
+Report duplicate keys in JSON documents and write them back out normalised.
+Reads standard input when no FILE, or `-`, is given.
+
+Options:
+  -p, --policy <POLICY>  Value kept for a repeated key: first, last (default), merge, collect or error
+  -c, --check            Only report duplicates, exiting with status 1 if any are found
+      --pretty           Write indented JSON
+  -q, --quiet            Do not report duplicates
+  -h, --help             Print this help
+
+Exit status is 0 on success, 1 when --check finds duplicates and 2 on any error.";
+
+/// How the values of a repeated key are normalised
+#[derive(Debug, Clone, Copy)]
+enum Normalisation {
+    /// Follow one of the crate's duplicate policies
+    Policy(DuplicatePolicy),
+
+    /// Apply repeated values over earlier ones as JSON Merge Patches, like a field annotated with `#[duplicates(merge)]`
+    Merge,
+}
+
+struct Options {
+    normalisation: Normalisation,
+    check: bool,
+    pretty: bool,
+    quiet: bool,
+    files: Vec<String>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut options = Self {
+            normalisation: Normalisation::Policy(DuplicatePolicy::Last),
+            check: false,
+            pretty: false,
+            quiet: false,
+            files: Vec::new(),
+        };
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag.to_owned(), Some(value.to_owned())),
+                _ => (arg.clone(), None),
+            };
+
+            match flag.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-c" | "--check" => options.check = true,
+                "--pretty" => options.pretty = true,
+                "-q" | "--quiet" => options.quiet = true,
+                "-p" | "--policy" => {
+                    let policy = inline_value
+                        .or_else(|| args.next())
+                        .ok_or_else(|| format!("{flag} requires a value"))?;
+
+                    options.normalisation = match policy.as_str() {
+                        "first" => Normalisation::Policy(DuplicatePolicy::First),
+                        "last" => Normalisation::Policy(DuplicatePolicy::Last),
+                        "error" => Normalisation::Policy(DuplicatePolicy::Error),
+                        "collect" => Normalisation::Policy(DuplicatePolicy::Collect),
+                        "merge" => Normalisation::Merge,
+                        _ => return Err(format!("unknown policy `{policy}`")),
+                    };
+                }
+                "--" => options.files.extend(args.by_ref()),
+                _ if flag.starts_with('-') && flag != "-" => return Err(format!("unknown option `{flag}`")),
+                _ => options.files.push(arg),
+            }
+        }
+
+        if options.files.is_empty() {
+            options.files.push("-".to_owned());
+        }
+
+        Ok(Some(options))
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("dedup-json: {error}\n\n{USAGE}");
+
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut found_duplicates = false;
+    let mut failed = false;
+
+    for file in &options.files {
+        match process(file, &options) {
+            Ok(has_duplicates) => found_duplicates |= has_duplicates,
+            Err(error) => {
+                eprintln!("dedup-json: {}: {error}", display_name(file));
+
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::from(2)
+    } else if options.check && found_duplicates {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// The name a file is reported under
+fn display_name(file: &str) -> &str {
+    if file == "-" {
+        "<stdin>"
+    } else {
+        file
+    }
+}
+
+/// Report the duplicates of a single document and, unless checking, write it normalised, returning whether any were found
+fn process(file: &str, options: &Options) -> Result<bool, Box<dyn std::error::Error>> {
+    let json = if file == "-" {
+        let mut json = String::new();
+
+        io::stdin().read_to_string(&mut json)?;
+
+        json
+    } else {
+        std::fs::read_to_string(file)?
+    };
+
+    let duplicates = find_duplicate_keys(&json)?;
+
+    if !options.quiet {
+        for duplicate in &duplicates {
+            eprintln!("{}: {duplicate}", display_name(file));
+        }
+    }
+
+    if options.check {
+        return Ok(!duplicates.is_empty());
+    }
+
+    let value: DuplicateValue = serde_json::from_str(&json)?;
+
+    let normalised = match options.normalisation {
+        Normalisation::Policy(policy) => value.to_value(policy)?,
+        Normalisation::Merge => value.to_merged_value()?,
+    };
+
+    let mut stdout = io::stdout().lock();
+
+    if options.pretty {
+        serde_json::to_writer_pretty(&mut stdout, &normalised)?;
+    } else {
+        serde_json::to_writer(&mut stdout, &normalised)?;
+    }
+
+    writeln!(stdout)?;
+
+    Ok(!duplicates.is_empty())
+}
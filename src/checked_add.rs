@@ -0,0 +1,71 @@
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::time::Duration;
+
+/// # Checked Add
+///
+/// Addition which reports overflow rather than panicking or wrapping. Fields using the `sum` strategy only need to
+/// implement [Add], but those whose type also implements this trait fail to deserialize when their total overflows.
+///
+/// It is implemented for every primitive integer type and [Duration].
+pub trait CheckedAdd: Sized {
+    /// Add two values, returning [None] if the result cannot be represented
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_add {
+    ($($type:ty),*) => {
+        $(
+            impl CheckedAdd for $type {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$type>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_add!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, Duration);
+
+/// Chooses how the occurrences of a `sum` field are added, through autoref specialization
+///
+/// Calling `(&Summation::<T>::new()).adder()` with both [CheckedSummation] and [UncheckedSummation] in scope resolves to
+/// [CheckedAdd] when `T` implements it, and otherwise falls back to [Add].
+pub struct Summation<T>(PhantomData<T>);
+
+impl<T> Summation<T> {
+    /// Choose how values of type `T` are added
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for Summation<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adds values implementing [CheckedAdd], returning [None] on overflow
+pub trait CheckedSummation<T> {
+    /// The function adding two values
+    fn adder(&self) -> fn(T, T) -> Option<T>;
+}
+
+impl<T: CheckedAdd> CheckedSummation<T> for Summation<T> {
+    fn adder(&self) -> fn(T, T) -> Option<T> {
+        CheckedAdd::checked_add
+    }
+}
+
+/// Adds values implementing [Add], following their own handling of overflow
+pub trait UncheckedSummation<T> {
+    /// The function adding two values
+    fn adder(&self) -> fn(T, T) -> Option<T>;
+}
+
+impl<T: Add<Output = T>> UncheckedSummation<T> for &Summation<T> {
+    fn adder(&self) -> fn(T, T) -> Option<T> {
+        |total, value| Some(total + value)
+    }
+}
@@ -0,0 +1,776 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, Error, MapAccess, SeqAccess, Unexpected,
+    VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+
+use crate::DuplicatePolicy;
+
+/// # Content
+///
+/// Any deserialized value held in memory, keeping every entry of its maps, duplicated or not, in the order they were found
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content<'de> {
+    /// A boolean
+    Bool(bool),
+
+    /// An unsigned 8 bit integer
+    U8(u8),
+
+    /// An unsigned 16 bit integer
+    U16(u16),
+
+    /// An unsigned 32 bit integer
+    U32(u32),
+
+    /// An unsigned 64 bit integer
+    U64(u64),
+
+    /// A signed 8 bit integer
+    I8(i8),
+
+    /// A signed 16 bit integer
+    I16(i16),
+
+    /// A signed 32 bit integer
+    I32(i32),
+
+    /// A signed 64 bit integer
+    I64(i64),
+
+    /// A 32 bit float
+    F32(f32),
+
+    /// A 64 bit float
+    F64(f64),
+
+    /// A single character
+    Char(char),
+
+    /// An owned string
+    String(String),
+
+    /// A string borrowed from the data
+    Str(&'de str),
+
+    /// An owned byte array
+    ByteBuf(Vec<u8>),
+
+    /// A byte array borrowed from the data
+    Bytes(&'de [u8]),
+
+    /// An absent optional value
+    None,
+
+    /// A present optional value
+    Some(Box<Content<'de>>),
+
+    /// A unit value
+    Unit,
+
+    /// A value wrapped in a newtype struct
+    Newtype(Box<Content<'de>>),
+
+    /// A sequence of values
+    Seq(Vec<Content<'de>>),
+
+    /// Every entry of a map in the order they were found, including duplicates
+    Map(Vec<(Content<'de>, Content<'de>)>),
+}
+
+impl Content<'_> {
+    /// The string held by this content, if it is one
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            Self::Str(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Describe this content for an error about its type
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self {
+            Self::Bool(value) => Unexpected::Bool(*value),
+            Self::U8(value) => Unexpected::Unsigned(*value as u64),
+            Self::U16(value) => Unexpected::Unsigned(*value as u64),
+            Self::U32(value) => Unexpected::Unsigned(*value as u64),
+            Self::U64(value) => Unexpected::Unsigned(*value),
+            Self::I8(value) => Unexpected::Signed(*value as i64),
+            Self::I16(value) => Unexpected::Signed(*value as i64),
+            Self::I32(value) => Unexpected::Signed(*value as i64),
+            Self::I64(value) => Unexpected::Signed(*value),
+            Self::F32(value) => Unexpected::Float(*value as f64),
+            Self::F64(value) => Unexpected::Float(*value),
+            Self::Char(value) => Unexpected::Char(*value),
+            Self::String(value) => Unexpected::Str(value),
+            Self::Str(value) => Unexpected::Str(value),
+            Self::ByteBuf(value) => Unexpected::Bytes(value),
+            Self::Bytes(value) => Unexpected::Bytes(value),
+            Self::None | Self::Some(_) => Unexpected::Option,
+            Self::Unit => Unexpected::Unit,
+            Self::Newtype(_) => Unexpected::NewtypeStruct,
+            Self::Seq(_) => Unexpected::Seq,
+            Self::Map(_) => Unexpected::Map,
+        }
+    }
+
+    /// Are two keys the same, comparing strings regardless of whether they were borrowed
+    fn is_same_key(&self, other: &Content) -> bool {
+        match (self.as_str(), other.as_str()) {
+            (Some(key), Some(other_key)) => key == other_key,
+            _ => self == other,
+        }
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Content<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(Content::Bool(value))
+    }
+
+    fn visit_u8<E: Error>(self, value: u8) -> Result<Self::Value, E> {
+        Ok(Content::U8(value))
+    }
+
+    fn visit_u16<E: Error>(self, value: u16) -> Result<Self::Value, E> {
+        Ok(Content::U16(value))
+    }
+
+    fn visit_u32<E: Error>(self, value: u32) -> Result<Self::Value, E> {
+        Ok(Content::U32(value))
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Content::U64(value))
+    }
+
+    fn visit_i8<E: Error>(self, value: i8) -> Result<Self::Value, E> {
+        Ok(Content::I8(value))
+    }
+
+    fn visit_i16<E: Error>(self, value: i16) -> Result<Self::Value, E> {
+        Ok(Content::I16(value))
+    }
+
+    fn visit_i32<E: Error>(self, value: i32) -> Result<Self::Value, E> {
+        Ok(Content::I32(value))
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Content::I64(value))
+    }
+
+    fn visit_f32<E: Error>(self, value: f32) -> Result<Self::Value, E> {
+        Ok(Content::F32(value))
+    }
+
+    fn visit_f64<E: Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Content::F64(value))
+    }
+
+    fn visit_char<E: Error>(self, value: char) -> Result<Self::Value, E> {
+        Ok(Content::Char(value))
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Content::String(value.to_owned()))
+    }
+
+    fn visit_borrowed_str<E: Error>(self, value: &'de str) -> Result<Self::Value, E> {
+        Ok(Content::Str(value))
+    }
+
+    fn visit_string<E: Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Content::String(value))
+    }
+
+    fn visit_bytes<E: Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(Content::ByteBuf(value.to_owned()))
+    }
+
+    fn visit_borrowed_bytes<E: Error>(self, value: &'de [u8]) -> Result<Self::Value, E> {
+        Ok(Content::Bytes(value))
+    }
+
+    fn visit_byte_buf<E: Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Content::ByteBuf(value))
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de::Deserialize::deserialize(deserializer).map(|value| Content::Some(Box::new(value)))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de::Deserialize::deserialize(deserializer).map(|value| Content::Newtype(Box::new(value)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or_default().min(4096));
+
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+
+        Ok(Content::Seq(elements))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or_default().min(4096));
+
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(Content::Map(entries))
+    }
+}
+
+/// Reduce the entries of a map so each key occurs once, following a [DuplicatePolicy]
+///
+/// Each key keeps the position of its first occurrence.
+pub fn deduplicate<'de, E: Error>(
+    entries: Vec<(Content<'de>, Content<'de>)>,
+    policy: DuplicatePolicy,
+) -> Result<Vec<(Content<'de>, Content<'de>)>, E> {
+    Ok(group_entries(entries, policy)?
+        .into_iter()
+        .map(|(key, mut values)| {
+            let value = match policy {
+                DuplicatePolicy::Last => values.pop(),
+                DuplicatePolicy::Collect if values.len() > 1 => Some(Content::Seq(values)),
+                _ => values.into_iter().next(),
+            };
+
+            (key, value.unwrap_or(Content::Unit))
+        })
+        .collect())
+}
+
+/// Reduce the entries of a map so each key occurs once, with a sequence of every value found under it, however many
+pub fn collect_entries<'de>(entries: Vec<(Content<'de>, Content<'de>)>) -> Vec<(Content<'de>, Content<'de>)> {
+    group_entries::<de::value::Error>(entries, DuplicatePolicy::Collect)
+        .expect("only the error policy rejects duplicates")
+        .into_iter()
+        .map(|(key, values)| (key, Content::Seq(values)))
+        .collect()
+}
+
+/// Group the values of a map by key, in the order each key first occurs, failing on a repeated key under the error policy
+fn group_entries<'de, E: Error>(
+    entries: Vec<(Content<'de>, Content<'de>)>,
+    policy: DuplicatePolicy,
+) -> Result<Vec<(Content<'de>, Vec<Content<'de>>)>, E> {
+    let mut groups: Vec<(Content<'de>, Vec<Content<'de>>)> = Vec::with_capacity(entries.len());
+
+    // String keys are looked up by name, any others by comparing against every key seen so far
+    let mut string_key_positions: HashMap<String, usize> = HashMap::new();
+
+    for (key, value) in entries {
+        let position = match key.as_str() {
+            Some(name) => string_key_positions.get(name).copied(),
+            None => groups.iter().position(|(seen_key, _)| seen_key.is_same_key(&key)),
+        };
+
+        match position {
+            Some(_) if policy == DuplicatePolicy::Error => {
+                return Err(match key.as_str() {
+                    Some(name) => E::custom(format_args!("duplicate key `{name}`")),
+                    None => E::custom(format_args!("duplicate key {:?}", key)),
+                });
+            }
+            Some(position) => groups[position].1.push(value),
+            None => {
+                if let Some(name) = key.as_str() {
+                    string_key_positions.insert(name.to_owned(), groups.len());
+                }
+
+                groups.push((key, vec![value]));
+            }
+        }
+    }
+
+    Ok(groups)
+}
+
+/// Apply one value over another following JSON Merge Patch (RFC 7396) semantics
+///
+/// Maps are merged recursively, a null value removing its key, while any other value replaces the target outright.
+/// Every entry of the target under a key is merged with the patch's value, so duplicates within it are kept.
+pub fn merge<'de>(target: &mut Content<'de>, patch: Content<'de>) {
+    let Content::Map(patch) = patch else {
+        *target = patch;
+        return;
+    };
+
+    if !matches!(target, Content::Map(_)) {
+        *target = Content::Map(Vec::new());
+    }
+
+    if let Content::Map(target) = target {
+        for (key, value) in patch {
+            if matches!(value, Content::Unit | Content::None) {
+                target.retain(|(target_key, _)| !target_key.is_same_key(&key));
+
+                continue;
+            }
+
+            let mut matching_entries = target
+                .iter_mut()
+                .filter(|(target_key, _)| target_key.is_same_key(&key))
+                .peekable();
+
+            if matching_entries.peek().is_none() {
+                let mut merged = Content::Map(Vec::new());
+
+                merge(&mut merged, value);
+
+                target.push((key, merged));
+            } else {
+                for (_, target_value) in matching_entries {
+                    merge(target_value, value.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Combine the values of every repeated key, within a value and every map nested in it, by applying each over the
+/// ones before it with [merge]
+#[cfg(feature = "serde_json")]
+pub fn merge_duplicates(content: Content<'_>) -> Content<'_> {
+    match content {
+        Content::Seq(values) => Content::Seq(values.into_iter().map(merge_duplicates).collect()),
+        Content::Map(entries) => Content::Map(
+            group_entries::<de::value::Error>(entries, DuplicatePolicy::Collect)
+                .expect("only the error policy rejects duplicates")
+                .into_iter()
+                .map(|(key, values)| {
+                    let merged = values
+                        .into_iter()
+                        .map(merge_duplicates)
+                        .reduce(|mut merged, value| {
+                            merge(&mut merged, value);
+                            merged
+                        });
+
+                    (key, merged.unwrap_or(Content::Unit))
+                })
+                .collect(),
+        ),
+        content => content,
+    }
+}
+
+/// Deserialize the only occurrence of a field collected under [DuplicatePolicy::Collect], as it is or, if it does not fit
+/// the field on its own, as the only element of a sequence
+///
+/// When neither fits, the error from deserializing the occurrence as it is is returned.
+pub fn deserialize_single_occurrence<'de, T, E: Error>(
+    occurrence: Content<'de>,
+    human_readable: bool,
+    deserialize: impl Fn(ContentDeserializer<'de, E>) -> Result<T, E>,
+) -> Result<T, E> {
+    deserialize(ContentDeserializer::preserving(occurrence.clone()).human_readable(human_readable)).or_else(|error| {
+        deserialize(ContentDeserializer::preserving(Content::Seq(vec![occurrence])).human_readable(human_readable))
+            .map_err(|_| error)
+    })
+}
+
+/// Reduce the entries of a map following a [DuplicatePolicy] if one is given, otherwise keeping every entry
+fn deduplicate_with<'de, E: Error>(
+    entries: Vec<(Content<'de>, Content<'de>)>,
+    policy: Option<DuplicatePolicy>,
+) -> Result<Vec<(Content<'de>, Content<'de>)>, E> {
+    match policy {
+        Some(policy) => deduplicate(entries, policy),
+        None => Ok(entries),
+    }
+}
+
+/// # Content Deserializer
+///
+/// Deserializes a value from [Content], applying a [DuplicatePolicy] to every map within it if one is given
+pub struct ContentDeserializer<'de, E> {
+    content: Content<'de>,
+    policy: Option<DuplicatePolicy>,
+    human_readable: bool,
+    error: PhantomData<E>,
+}
+
+impl<'de, E> ContentDeserializer<'de, E> {
+    /// Deserialize from the given content following the given policy
+    pub fn new(content: Content<'de>, policy: DuplicatePolicy) -> Self {
+        Self::with_policy(content, Some(policy), true)
+    }
+
+    /// Deserialize from the given content, handing every entry of its maps to the visitor as they were found
+    pub fn preserving(content: Content<'de>) -> Self {
+        Self::with_policy(content, None, true)
+    }
+
+    /// Set whether the content is reported as coming from a human readable format, as given by the deserializer it was
+    /// buffered from. Content is reported as human readable unless set otherwise.
+    pub fn human_readable(mut self, human_readable: bool) -> Self {
+        self.human_readable = human_readable;
+        self
+    }
+
+    fn with_policy(content: Content<'de>, policy: Option<DuplicatePolicy>, human_readable: bool) -> Self {
+        Self {
+            content,
+            policy,
+            human_readable,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: Error> Deserializer<'de> for ContentDeserializer<'de, E> {
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        let policy = self.policy;
+        let human_readable = self.human_readable;
+
+        match self.content {
+            Content::Bool(value) => visitor.visit_bool(value),
+            Content::U8(value) => visitor.visit_u8(value),
+            Content::U16(value) => visitor.visit_u16(value),
+            Content::U32(value) => visitor.visit_u32(value),
+            Content::U64(value) => visitor.visit_u64(value),
+            Content::I8(value) => visitor.visit_i8(value),
+            Content::I16(value) => visitor.visit_i16(value),
+            Content::I32(value) => visitor.visit_i32(value),
+            Content::I64(value) => visitor.visit_i64(value),
+            Content::F32(value) => visitor.visit_f32(value),
+            Content::F64(value) => visitor.visit_f64(value),
+            Content::Char(value) => visitor.visit_char(value),
+            Content::String(value) => visitor.visit_string(value),
+            Content::Str(value) => visitor.visit_borrowed_str(value),
+            Content::ByteBuf(value) => visitor.visit_byte_buf(value),
+            Content::Bytes(value) => visitor.visit_borrowed_bytes(value),
+            Content::None => visitor.visit_none(),
+            Content::Some(value) => visitor.visit_some(Self::with_policy(*value, policy, human_readable)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(value) => visitor.visit_newtype_struct(Self::with_policy(*value, policy, human_readable)),
+            Content::Seq(elements) => visitor.visit_seq(ContentSeqAccess::new(elements, policy, human_readable)),
+            Content::Map(entries) => {
+                visitor.visit_map(ContentMapAccess::new(deduplicate_with(entries, policy)?, policy, human_readable))
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(value) => visitor.visit_some(Self::with_policy(*value, self.policy, self.human_readable)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::Newtype(value) => {
+                visitor.visit_newtype_struct(Self::with_policy(*value, self.policy, self.human_readable))
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        let policy = self.policy;
+        let human_readable = self.human_readable;
+
+        let (variant, value) = match self.content {
+            variant @ (Content::String(_) | Content::Str(_)) => (variant, None),
+            Content::Map(entries) => {
+                let mut entries = deduplicate_with::<E>(entries, policy)?.into_iter();
+
+                match (entries.next(), entries.next()) {
+                    (Some((variant, value)), None) => (variant, Some(value)),
+                    _ => return Err(E::invalid_value(Unexpected::Map, &"a map with a single key")),
+                }
+            }
+            other => return Err(E::invalid_type(other.unexpected(), &"a string or a map")),
+        };
+
+        visitor.visit_enum(ContentEnumAccess {
+            variant,
+            value,
+            policy,
+            human_readable,
+            error: PhantomData,
+        })
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        let human_readable = self.human_readable;
+
+        match (self.content, self.policy) {
+            // Keys found once are kept as they are, so a lone value stands for a sequence of one when collecting
+            (Content::Seq(elements), policy) => {
+                visitor.visit_seq(ContentSeqAccess::new(elements, policy, human_readable))
+            }
+            (content, Some(DuplicatePolicy::Collect)) => visitor.visit_seq(ContentSeqAccess::new(
+                vec![content],
+                Some(DuplicatePolicy::Collect),
+                human_readable,
+            )),
+            (content, policy) => Self::with_policy(content, policy, human_readable).deserialize_any(visitor),
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct ContentSeqAccess<'de, E> {
+    elements: std::vec::IntoIter<Content<'de>>,
+    policy: Option<DuplicatePolicy>,
+    human_readable: bool,
+    error: PhantomData<E>,
+}
+
+impl<'de, E> ContentSeqAccess<'de, E> {
+    fn new(elements: Vec<Content<'de>>, policy: Option<DuplicatePolicy>, human_readable: bool) -> Self {
+        Self {
+            elements: elements.into_iter(),
+            policy,
+            human_readable,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: Error> SeqAccess<'de> for ContentSeqAccess<'de, E> {
+    type Error = E;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, E>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.elements
+            .next()
+            .map(|element| {
+                seed.deserialize(ContentDeserializer::with_policy(element, self.policy, self.human_readable))
+            })
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+struct ContentMapAccess<'de, E> {
+    entries: std::vec::IntoIter<(Content<'de>, Content<'de>)>,
+    value: Option<Content<'de>>,
+    policy: Option<DuplicatePolicy>,
+    human_readable: bool,
+    error: PhantomData<E>,
+}
+
+impl<'de, E> ContentMapAccess<'de, E> {
+    fn new(entries: Vec<(Content<'de>, Content<'de>)>, policy: Option<DuplicatePolicy>, human_readable: bool) -> Self {
+        Self {
+            entries: entries.into_iter(),
+            value: None,
+            policy,
+            human_readable,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: Error> MapAccess<'de> for ContentMapAccess<'de, E> {
+    type Error = E;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, E>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+
+        self.value = Some(value);
+
+        seed.deserialize(ContentDeserializer::with_policy(key, self.policy, self.human_readable)).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, E>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let value = self.value.take().ok_or_else(|| E::custom("value requested before its key"))?;
+
+        seed.deserialize(ContentDeserializer::with_policy(value, self.policy, self.human_readable))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+struct ContentEnumAccess<'de, E> {
+    variant: Content<'de>,
+    value: Option<Content<'de>>,
+    policy: Option<DuplicatePolicy>,
+    human_readable: bool,
+    error: PhantomData<E>,
+}
+
+impl<'de, E: Error> EnumAccess<'de> for ContentEnumAccess<'de, E> {
+    type Error = E;
+    type Variant = ContentVariantAccess<'de, E>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), E>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(ContentDeserializer::with_policy(
+            self.variant,
+            self.policy,
+            self.human_readable,
+        ))?;
+
+        Ok((
+            variant,
+            ContentVariantAccess {
+                value: self.value,
+                policy: self.policy,
+                human_readable: self.human_readable,
+                error: PhantomData,
+            },
+        ))
+    }
+}
+
+struct ContentVariantAccess<'de, E> {
+    value: Option<Content<'de>>,
+    policy: Option<DuplicatePolicy>,
+    human_readable: bool,
+    error: PhantomData<E>,
+}
+
+impl<'de, E: Error> VariantAccess<'de> for ContentVariantAccess<'de, E> {
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.value {
+            None | Some(Content::Unit) => Ok(()),
+            Some(other) => Err(E::invalid_type(other.unexpected(), &"a unit variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, E>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.value {
+            Some(value) => seed.deserialize(ContentDeserializer::with_policy(value, self.policy, self.human_readable)),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"a newtype variant")),
+        }
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(Content::Seq(elements)) => {
+                visitor.visit_seq(ContentSeqAccess::new(elements, self.policy, self.human_readable))
+            }
+            Some(other) => Err(E::invalid_type(other.unexpected(), &"a tuple variant")),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"a tuple variant")),
+        }
+    }
+
+    fn struct_variant<V>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(Content::Map(entries)) => {
+                visitor.visit_map(ContentMapAccess::new(
+                deduplicate_with(entries, self.policy)?,
+                self.policy,
+                self.human_readable,
+            ))
+            }
+            Some(Content::Seq(elements)) => {
+                visitor.visit_seq(ContentSeqAccess::new(elements, self.policy, self.human_readable))
+            }
+            Some(other) => Err(E::invalid_type(other.unexpected(), &"a struct variant")),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"a struct variant")),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::cell::RefCell;
+use std::thread::LocalKey;
+
+use crate::{DuplicatePolicy, DuplicateReport, DuplicateWarning, DuplicatedField};
+
+thread_local! {
+    /// The report being collected on this thread, if any
+    static REPORT: RefCell<Option<DuplicateReport>> = const { RefCell::new(None) };
+
+    /// The warnings being collected on this thread, if any
+    static WARNINGS: RefCell<Option<Vec<DuplicateWarning>>> = const { RefCell::new(None) };
+
+    /// The policy followed on this thread, if any overrides the derives' own
+    static POLICY: RefCell<Option<ScopedPolicy>> = const { RefCell::new(None) };
+}
+
+/// A policy overriding the derives' own, and whether it was chosen at runtime rather than propagated by a struct
+#[derive(Clone, Copy)]
+struct ScopedPolicy {
+    policy: DuplicatePolicy,
+    chosen_at_runtime: bool,
+}
+
+/// Restores the value previously held by a context when dropped, so nested and panicking collections leave no trace
+struct ContextGuard<T: 'static> {
+    context: &'static LocalKey<RefCell<Option<T>>>,
+    previous: Option<T>,
+}
+
+impl<T: 'static> Drop for ContextGuard<T> {
+    fn drop(&mut self) {
+        self.context.set(self.previous.take());
+    }
+}
+
+/// Run a function with the given value held by a context, returning the value it was left with
+fn scoped<T: 'static, R>(context: &'static LocalKey<RefCell<Option<T>>>, value: T, function: impl FnOnce() -> R) -> (R, Option<T>) {
+    let guard = ContextGuard {
+        context,
+        previous: context.replace(Some(value)),
+    };
+
+    let result = function();
+
+    let value = context.take();
+
+    drop(guard);
+
+    (result, value)
+}
+
+/// Run a function with a fresh value in the given context, returning what it collected there
+fn collect<T: Default + 'static, R>(context: &'static LocalKey<RefCell<Option<T>>>, function: impl FnOnce() -> R) -> (R, T) {
+    let (result, collected) = scoped(context, T::default(), function);
+
+    (result, collected.unwrap_or_default())
+}
+
+/// Run a function while collecting a [DuplicateReport] of every duplicated field it deserializes
+pub fn collect_report<T>(function: impl FnOnce() -> T) -> (T, DuplicateReport) {
+    collect(&REPORT, function)
+}
+
+/// Run a function while collecting every [DuplicateWarning] raised while it deserializes
+pub fn collect_warnings<T>(function: impl FnOnce() -> T) -> (T, Vec<DuplicateWarning>) {
+    collect(&WARNINGS, function)
+}
+
+/// # Policy Scope
+///
+/// Has every derived struct deserialized on this thread follow a policy until dropped, restoring the one previously followed
+pub struct PolicyScope {
+    _guard: ContextGuard<ScopedPolicy>,
+}
+
+impl PolicyScope {
+    /// Start following the given policy, propagated by a struct to those nested within it
+    ///
+    /// A struct only propagates a policy chosen at runtime if one is being followed, so it is still treated as such
+    pub fn enter(policy: DuplicatePolicy) -> Self {
+        let chosen_at_runtime = POLICY.with_borrow(|scoped_policy| scoped_policy.is_some_and(|scoped| scoped.chosen_at_runtime));
+
+        Self {
+            _guard: ContextGuard {
+                context: &POLICY,
+                previous: POLICY.replace(Some(ScopedPolicy {
+                    policy,
+                    chosen_at_runtime,
+                })),
+            },
+        }
+    }
+}
+
+/// Run a function with every derived struct it deserializes following the given policy
+pub fn with_policy<T>(policy: DuplicatePolicy, function: impl FnOnce() -> T) -> T {
+    let scoped_policy = ScopedPolicy {
+        policy,
+        chosen_at_runtime: true,
+    };
+
+    scoped(&POLICY, scoped_policy, function).0
+}
+
+/// The policy overriding the derives' own on this thread, if any
+pub fn current_policy() -> Option<DuplicatePolicy> {
+    POLICY.with_borrow(|scoped_policy| scoped_policy.map(|scoped| scoped.policy))
+}
+
+/// The policy chosen at runtime to override the derives' own on this thread, if any, ignoring one propagated by a struct
+pub fn runtime_policy() -> Option<DuplicatePolicy> {
+    POLICY.with_borrow(|scoped_policy| {
+        scoped_policy
+            .filter(|scoped| scoped.chosen_at_runtime)
+            .map(|scoped| scoped.policy)
+    })
+}
+
+/// Record a duplicated field in the report being collected, if any
+pub fn record_duplicate(type_name: &'static str, field: &'static str, keys: &[impl AsRef<str>], selected: Option<usize>) {
+    REPORT.with_borrow_mut(|report| {
+        if let Some(report) = report {
+            report.duplicates.push(DuplicatedField {
+                type_name,
+                field,
+                keys: keys.iter().map(|key| key.as_ref().to_owned()).collect(),
+                selected,
+            });
+        }
+    });
+}
+
+/// Record a warning in the warnings being collected, if any
+///
+/// The warning is only built when something is collecting it
+pub fn record_warning(warning: impl FnOnce() -> DuplicateWarning) {
+    WARNINGS.with_borrow_mut(|warnings| {
+        if let Some(warnings) = warnings {
+            warnings.push(warning());
+        }
+    });
+}
@@ -0,0 +1,121 @@
+use serde::de::{Deserialize, Deserializer, Visitor};
+
+use crate::content::{Content, ContentDeserializer};
+use crate::DuplicatePolicy;
+
+/// # Deduplicating Deserializer
+///
+/// Wraps any [Deserializer] so that every map in its input, however deeply nested, has its duplicate keys removed following
+/// a [DuplicatePolicy] before the visitor of the type being deserialized sees it. This applies duplicate handling to types
+/// which cannot be annotated, such as those from other crates using serde's own derive.
+///
+/// The whole input is buffered in memory before being handed to the visitor, so the wrapped deserializer must be self-describing.
+/// Under [DuplicatePolicy::Error] deserialization fails on the first repeated key of any map.
+///
+/// ## Usage
+///
+///```rust
+///use serde::Deserialize;
+///use serde_deserialize_duplicates::{DeduplicatingDeserializer, DuplicatePolicy};
+///
+///#[derive(Deserialize)]
+///struct Reading {
+///    pub values: Vec<u32>
+///}
+///
+///let mut json = serde_json::Deserializer::from_str(r#"{ "values": 1, "values": 2 }"#);
+///
+///let reading = Reading::deserialize(DeduplicatingDeserializer::new(&mut json, DuplicatePolicy::Collect)).unwrap();
+///
+///assert_eq!(reading.values, vec![1, 2]);
+///```
+pub struct DeduplicatingDeserializer<D> {
+    deserializer: D,
+    policy: DuplicatePolicy,
+}
+
+impl<D> DeduplicatingDeserializer<D> {
+    /// Wrap a deserializer, removing the duplicate keys of every map in its input following the given policy
+    pub fn new(deserializer: D, policy: DuplicatePolicy) -> Self {
+        Self { deserializer, policy }
+    }
+
+    /// Unwrap the original deserializer
+    pub fn into_inner(self) -> D {
+        self.deserializer
+    }
+}
+
+impl<'de, D: Deserializer<'de>> DeduplicatingDeserializer<D> {
+    /// Buffer the whole input, ready to be deserialized following the policy
+    fn buffer(self) -> Result<ContentDeserializer<'de, D::Error>, D::Error> {
+        let human_readable = self.deserializer.is_human_readable();
+        let content = Content::deserialize(self.deserializer)?;
+
+        Ok(ContentDeserializer::new(content, self.policy).human_readable(human_readable))
+    }
+}
+
+/// Deserialize a `T` after removing the duplicate keys of every map in the input following a policy, see [DeduplicatingDeserializer]
+pub fn deserialize_deduplicated<'de, T, D>(deserializer: D, policy: DuplicatePolicy) -> Result<T, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(DeduplicatingDeserializer::new(deserializer, policy))
+}
+
+macro_rules! forward_to_buffered {
+    ($($method:ident($($argument:ident: $argument_type:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V>(self, $($argument: $argument_type,)* visitor: V) -> Result<V::Value, D::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.buffer()?.$method($($argument,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for DeduplicatingDeserializer<D> {
+    type Error = D::Error;
+
+    forward_to_buffered! {
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+}
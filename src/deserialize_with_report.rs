@@ -0,0 +1,39 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::{context, DuplicateReport};
+
+/// # Deserialize With Report
+///
+/// Deserialize a value, returning it alongside a [DuplicateReport] of every duplicated field found in any struct
+/// deriving [DeserializeFirstDuplicate](crate::DeserializeFirstDuplicate) or [DeserializeLastDuplicate](crate::DeserializeLastDuplicate)
+/// within it.
+///
+/// ## Usage
+///
+///```rust
+///use serde::Serialize;
+///use serde_deserialize_duplicates::{deserialize_with_report, DeserializeLastDuplicate};
+///
+///#[derive(Serialize, DeserializeLastDuplicate)]
+///struct Dog {
+///    #[serde(alias = "type")]
+///    pub breed: String
+///}
+///
+///let mut deserializer = serde_json::Deserializer::from_str(r#"{ "type": "Labrador", "breed": "Retriever" }"#);
+///
+///let (dog, report) = deserialize_with_report::<Dog, _>(&mut deserializer).unwrap();
+///
+///assert_eq!(dog.breed, "Retriever".to_owned());
+///assert_eq!(report.duplicates[0].keys, vec!["type".to_owned(), "breed".to_owned()]);
+///assert_eq!(report.duplicates[0].selected, Some(1));
+///```
+pub fn deserialize_with_report<'de, T, D>(deserializer: D) -> Result<(T, DuplicateReport), D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let (value, report) = context::collect_report(|| T::deserialize(deserializer));
+
+    value.map(|value| (value, report))
+}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+use crate::context::record_warning;
+use crate::DuplicateWarning;
+
+/// Note that an occurrence of a duplicated field was discarded, emitting a tracing event when the `tracing` feature is enabled
+#[inline]
+pub fn discard_duplicate(type_name: &'static str, field: &'static str, key: &str, occurrence: usize) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(type_name, field, key, occurrence, "discarded duplicate field occurrence");
+
+    record_warning(|| DuplicateWarning::DiscardedDuplicate {
+        type_name,
+        field,
+        key: key.to_owned(),
+        occurrence,
+    });
+}
+
+/// Note that an occurrence of a field was skipped as it could not be deserialized
+#[inline]
+pub fn skip_invalid_value(type_name: &'static str, field: &'static str, key: &str, occurrence: usize, error: &dyn Display) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(type_name, field, key, occurrence, %error, "skipped invalid field occurrence");
+
+    record_warning(|| DuplicateWarning::SkippedInvalidValue {
+        type_name,
+        field,
+        key: key.to_owned(),
+        occurrence,
+        error: error.to_string(),
+    });
+}
+
+/// Note that a field was given its default value as none of its occurrences could be deserialized
+#[inline]
+pub fn use_default(type_name: &'static str, field: &'static str, errors: &[String]) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(type_name, field, errors = %errors.join("; "), "used default value for field");
+
+    record_warning(|| DuplicateWarning::DefaultUsed {
+        type_name,
+        field,
+        errors: errors.to_vec(),
+    });
+}
@@ -0,0 +1,107 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{deserialize_deduplicated, DuplicatePolicy};
+
+/// # First Duplicate
+///
+/// Wraps any `T` implementing [Deserialize], such as a type from another crate, so that it is deserialized using the first
+/// occurrence of every duplicated key in every map within it, however deeply nested.
+///
+/// The whole value is buffered in memory before `T` is deserialized from it.
+///
+/// ## Usage
+///
+///```rust
+///use serde::Deserialize;
+///use serde_deserialize_duplicates::FirstDuplicate;
+///
+///#[derive(Deserialize)]
+///struct Dog {
+///    pub breed: String
+///}
+///
+///let dog: FirstDuplicate<Dog> = serde_json::from_str(r#"{ "breed": "Labrador", "breed": "Retriever" }"#).unwrap();
+///
+///assert_eq!(dog.breed, "Labrador".to_owned());
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FirstDuplicate<T>(pub T);
+
+/// # Last Duplicate
+///
+/// Wraps any `T` implementing [Deserialize], such as a type from another crate, so that it is deserialized using the last
+/// occurrence of every duplicated key in every map within it, however deeply nested.
+///
+/// The whole value is buffered in memory before `T` is deserialized from it.
+///
+/// ## Usage
+///
+///```rust
+///use serde::Deserialize;
+///use serde_deserialize_duplicates::LastDuplicate;
+///
+///#[derive(Deserialize)]
+///struct Dog {
+///    pub breed: String
+///}
+///
+///let dog: LastDuplicate<Dog> = serde_json::from_str(r#"{ "breed": "Labrador", "breed": "Retriever" }"#).unwrap();
+///
+///assert_eq!(dog.breed, "Retriever".to_owned());
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LastDuplicate<T>(pub T);
+
+macro_rules! impl_duplicate_adapter {
+    ($adapter:ident, $policy:expr) => {
+        impl<T> $adapter<T> {
+            /// Unwrap the deserialized value
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> Deref for $adapter<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $adapter<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+
+        impl<T> From<T> for $adapter<T> {
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<'de, T: Deserialize<'de>> Deserialize<'de> for $adapter<T> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_deduplicated(deserializer, $policy).map(Self)
+            }
+        }
+
+        impl<T: Serialize> Serialize for $adapter<T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+    };
+}
+
+impl_duplicate_adapter!(FirstDuplicate, DuplicatePolicy::First);
+impl_duplicate_adapter!(LastDuplicate, DuplicatePolicy::Last);
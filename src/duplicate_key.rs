@@ -0,0 +1,60 @@
+use std::fmt;
+
+/// # Duplicate Key
+///
+/// A key found more than once within the same object of a JSON document, see [find_duplicate_keys](crate::find_duplicate_keys)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKey {
+    /// The JSON Pointer (RFC 6901) of the object containing the key, empty for the root of the document
+    pub pointer: String,
+
+    /// The key, with any escape sequences decoded
+    pub key: String,
+
+    /// Where each occurrence of the key starts, in order
+    pub occurrences: Vec<TextPosition>,
+}
+
+impl fmt::Display for DuplicateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "key `{}` repeated {} times in ", self.key, self.occurrences.len())?;
+
+        // The root's pointer is empty, while `/` points at the member of the root named by the empty string
+        if self.pointer.is_empty() {
+            f.write_str("the root object at ")?;
+        } else {
+            write!(f, "object `{}` at ", self.pointer)?;
+        }
+
+        for (index, occurrence) in self.occurrences.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{occurrence}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// # Text Position
+///
+/// A position within a JSON document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TextPosition {
+    /// The number of bytes before the position
+    pub offset: usize,
+
+    /// The line of the position, starting at 1
+    pub line: usize,
+
+    /// The character within the line of the position, starting at 1
+    pub column: usize,
+}
+
+impl fmt::Display for TextPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} column {}", self.line, self.column)
+    }
+}
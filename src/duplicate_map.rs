@@ -0,0 +1,222 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// # Duplicate Map
+///
+/// A map keeping every entry in the order it was found, including every occurrence of a repeated key. Deserializing into it
+/// never loses a duplicate, and serializing it reproduces every repeated key in its original position.
+///
+/// ## Usage
+///
+///```rust
+///use serde_deserialize_duplicates::DuplicateMap;
+///
+///let map: DuplicateMap<String, u32> = serde_json::from_str(r#"{ "a": 1, "b": 2, "a": 3 }"#).unwrap();
+///
+///assert_eq!(map.get_first("a"), Some(&1));
+///assert_eq!(map.get_last("a"), Some(&3));
+///assert_eq!(map.get_all("a").collect::<Vec<_>>(), vec![&1, &3]);
+///assert_eq!(map.len(), 3);
+///```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DuplicateMap<K, V> {
+    entries: Vec<(K, V)>,
+}
+
+impl<K, V> DuplicateMap<K, V> {
+    /// Create an empty map
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// The number of entries, counting every occurrence of a repeated key
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Does the map have no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add an entry after every existing one, regardless of whether its key is already present
+    pub fn push(&mut self, key: K, value: V) {
+        self.entries.push((key, value));
+    }
+
+    /// Iterate over every entry in order
+    pub fn iter(&self) -> std::slice::Iter<'_, (K, V)> {
+        self.entries.iter()
+    }
+
+    /// Iterate over the key of every entry in order, repeated keys included
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// Iterate over the value of every entry in order
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// The entries of the map, in order
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.entries
+    }
+
+    /// Unwrap the entries of the map, in order
+    pub fn into_vec(self) -> Vec<(K, V)> {
+        self.entries
+    }
+
+    /// The value of the first entry with the given key
+    pub fn get_first<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key.borrow() == key)
+            .map(|(_, value)| value)
+    }
+
+    /// The value of the last entry with the given key
+    pub fn get_last<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(entry_key, _)| entry_key.borrow() == key)
+            .map(|(_, value)| value)
+    }
+
+    /// The values of every entry with the given key, in order
+    pub fn get_all<'a, Q>(&'a self, key: &'a Q) -> impl DoubleEndedIterator<Item = &'a V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.entries
+            .iter()
+            .filter(move |(entry_key, _)| entry_key.borrow() == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Does any entry have the given key
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.get_first(key).is_some()
+    }
+
+    /// How many entries have the given key
+    pub fn count<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.get_all(key).count()
+    }
+}
+
+impl<K, V> Default for DuplicateMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> From<Vec<(K, V)>> for DuplicateMap<K, V> {
+    fn from(entries: Vec<(K, V)>) -> Self {
+        Self { entries }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for DuplicateMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<K, V> Extend<(K, V)> for DuplicateMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.entries.extend(iter);
+    }
+}
+
+impl<K, V> IntoIterator for DuplicateMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a DuplicateMap<K, V> {
+    type Item = &'a (K, V);
+    type IntoIter = std::slice::Iter<'a, (K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'de, K: Deserialize<'de>, V: Deserialize<'de>> Deserialize<'de> for DuplicateMap<K, V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DuplicateMapVisitor(PhantomData))
+    }
+}
+
+struct DuplicateMapVisitor<K, V>(PhantomData<fn() -> (K, V)>);
+
+impl<'de, K: Deserialize<'de>, V: Deserialize<'de>> Visitor<'de> for DuplicateMapVisitor<K, V> {
+    type Value = DuplicateMap<K, V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or_default().min(4096));
+
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(DuplicateMap { entries })
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for DuplicateMap<K, V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.entries.len()))?;
+
+        for (key, value) in &self.entries {
+            map.serialize_entry(key, value)?;
+        }
+
+        map.end()
+    }
+}
@@ -0,0 +1,26 @@
+/// # Duplicate Policy
+///
+/// How duplicated fields are handled when chosen at runtime rather than through the choice of derive, see [WithPolicy](crate::WithPolicy)
+///
+/// A policy only governs fields without a strategy of their own set through the `#[duplicates(...)]` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DuplicatePolicy {
+    /// Use the first occurrence of a field, ignoring the rest
+    First,
+
+    /// Use the last occurrence of a field
+    Last,
+
+    /// Fail deserialization when a field occurs more than once
+    Error,
+
+    /// Deserialize a field occurring more than once from a sequence of every occurrence, in order
+    ///
+    /// Each occurrence is one element of the sequence, so a field repeated with arrays needs a sequence of sequences
+    /// such as `Vec<Vec<T>>`. A field found only once is deserialized from its value as it is, unless that value does not
+    /// fit, in which case it is deserialized from a sequence holding it as the only element, so a `Vec<T>` field accepts
+    /// a single `T`. Within types which do not derive this crate's macros, such as those deserialized through
+    /// [DeduplicatingDeserializer](crate::DeduplicatingDeserializer), a value only stands for a sequence of one when a
+    /// sequence is expected in its place and it is not one.
+    Collect,
+}
@@ -0,0 +1,54 @@
+/// # Duplicate Report
+///
+/// A report of every key which was repeated while deserializing a value, see [deserialize_with_report](crate::deserialize_with_report)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DuplicateReport {
+    /// Every duplicated field, in the order their structs finished deserializing
+    pub duplicates: Vec<DuplicatedField>,
+}
+
+impl DuplicateReport {
+    /// Were no duplicates found
+    pub fn is_empty(&self) -> bool {
+        self.duplicates.is_empty()
+    }
+
+    /// Iterate over every duplicated field
+    pub fn iter(&self) -> std::slice::Iter<'_, DuplicatedField> {
+        self.duplicates.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a DuplicateReport {
+    type Item = &'a DuplicatedField;
+    type IntoIter = std::slice::Iter<'a, DuplicatedField>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// # Duplicated Field
+///
+/// A field of a struct which was found more than once, under its name or any of its aliases
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicatedField {
+    /// The name of the struct the field belongs to
+    pub type_name: &'static str,
+
+    /// The name of the field
+    pub field: &'static str,
+
+    /// The key each occurrence was found under, in order
+    pub keys: Vec<String>,
+
+    /// The index of the occurrence used as the field's value, or [None] if occurrences were combined or none could be used as is
+    pub selected: Option<usize>,
+}
+
+impl DuplicatedField {
+    /// How many times the field occurred
+    pub fn occurrences(&self) -> usize {
+        self.keys.len()
+    }
+}
@@ -0,0 +1,296 @@
+use std::fmt;
+
+use serde::de::{DeserializeOwned, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Number, Value};
+
+use crate::content::{merge_duplicates, Content, ContentDeserializer};
+use crate::{DuplicateMap, DuplicatePolicy};
+
+/// # Duplicate Value
+///
+/// Any JSON value, mirroring [serde_json::Value], except that objects keep every entry in the order it was found, including
+/// every occurrence of a repeated key. Documents can be inspected, logged and replayed faithfully, as serializing reproduces
+/// every repeated key.
+///
+/// ## Usage
+///
+///```rust
+///use serde::Serialize;
+///use serde_deserialize_duplicates::{DeserializeLastDuplicate, DuplicatePolicy, DuplicateValue};
+///
+///#[derive(Serialize, DeserializeLastDuplicate)]
+///struct Dog {
+///    pub breed: String
+///}
+///
+///let value: DuplicateValue = serde_json::from_str(r#"{ "breed": "Labrador", "breed": "Retriever" }"#).unwrap();
+///
+///assert!(value.has_duplicates());
+///assert_eq!(value.to_string(), r#"{"breed":"Labrador","breed":"Retriever"}"#);
+///
+///assert_eq!(value.to_value(DuplicatePolicy::First).unwrap(), serde_json::json!({ "breed": "Labrador" }));
+///
+///let dog: Dog = value.deserialize_into().unwrap();
+///
+///assert_eq!(dog.breed, "Retriever".to_owned());
+///```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum DuplicateValue {
+    /// A null value
+    #[default]
+    Null,
+
+    /// A boolean
+    Bool(bool),
+
+    /// A number
+    Number(Number),
+
+    /// A string
+    String(String),
+
+    /// An array of values
+    Array(Vec<DuplicateValue>),
+
+    /// An object, keeping every entry in order
+    Object(DuplicateMap<String, DuplicateValue>),
+}
+
+impl DuplicateValue {
+    /// Is this value null
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// The boolean held by this value, if it is one
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The number held by this value, if it is one
+    pub fn as_number(&self) -> Option<&Number> {
+        match self {
+            Self::Number(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The string held by this value, if it is one
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The values held by this array, if it is one
+    pub fn as_array(&self) -> Option<&[DuplicateValue]> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// The entries held by this object, if it is one
+    pub fn as_object(&self) -> Option<&DuplicateMap<String, DuplicateValue>> {
+        match self {
+            Self::Object(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// Does any object within this value, however deeply nested, repeat a key
+    pub fn has_duplicates(&self) -> bool {
+        match self {
+            Self::Array(values) => values.iter().any(Self::has_duplicates),
+            Self::Object(entries) => {
+                entries
+                    .iter()
+                    .enumerate()
+                    .any(|(index, (key, _))| entries.keys().skip(index + 1).any(|other_key| other_key == key))
+                    || entries.values().any(Self::has_duplicates)
+            }
+            _ => false,
+        }
+    }
+
+    /// Convert into a [serde_json::Value], removing the duplicate keys of every object following a policy
+    pub fn to_value(&self, policy: DuplicatePolicy) -> Result<Value, serde_json::Error> {
+        Value::deserialize(ContentDeserializer::new(self.to_content(), policy))
+    }
+
+    /// Convert into a [serde_json::Value], combining the values of the repeated keys of every object as a field annotated
+    /// with `#[duplicates(merge)]` would: each is applied over the ones before it as a JSON Merge Patch (RFC 7396), so
+    /// objects are merged recursively, a null removes its key and any other value replaces the previous one
+    pub fn to_merged_value(&self) -> Result<Value, serde_json::Error> {
+        Value::deserialize(ContentDeserializer::preserving(merge_duplicates(self.to_content())))
+    }
+
+    /// Deserialize a `T` from this value, with every entry of its objects seen exactly as they were found, so derived
+    /// structs apply their own duplicate handling
+    pub fn deserialize_into<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(ContentDeserializer::preserving(self.to_content()))
+    }
+
+    /// Deserialize a `T` from this value, removing the duplicate keys of every object following a policy first
+    pub fn deserialize_with_policy<T: DeserializeOwned>(&self, policy: DuplicatePolicy) -> Result<T, serde_json::Error> {
+        T::deserialize(ContentDeserializer::new(self.to_content(), policy))
+    }
+
+    /// Borrow this value as [Content] to be deserialized from
+    fn to_content(&self) -> Content<'_> {
+        match self {
+            Self::Null => Content::Unit,
+            Self::Bool(value) => Content::Bool(*value),
+            Self::Number(value) => match (value.as_u64(), value.as_i64(), value.as_f64()) {
+                (Some(value), _, _) => Content::U64(value),
+                (None, Some(value), _) => Content::I64(value),
+                (None, None, Some(value)) => Content::F64(value),
+                (None, None, None) => Content::String(value.to_string()),
+            },
+            Self::String(value) => Content::Str(value),
+            Self::Array(values) => Content::Seq(values.iter().map(Self::to_content).collect()),
+            Self::Object(entries) => Content::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (Content::Str(key), value.to_content()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for DuplicateValue {
+    /// Write this value as compact JSON, repeating duplicated keys
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+
+        f.write_str(&json)
+    }
+}
+
+impl From<Value> for DuplicateValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(value) => Self::Bool(value),
+            Value::Number(value) => Self::Number(value),
+            Value::String(value) => Self::String(value),
+            Value::Array(values) => Self::Array(values.into_iter().map(Self::from).collect()),
+            Value::Object(entries) => Self::Object(entries.into_iter().map(|(key, value)| (key, value.into())).collect()),
+        }
+    }
+}
+
+impl From<Map<String, Value>> for DuplicateValue {
+    fn from(entries: Map<String, Value>) -> Self {
+        Value::Object(entries).into()
+    }
+}
+
+impl TryFrom<DuplicateValue> for Value {
+    type Error = serde_json::Error;
+
+    /// Convert into a [serde_json::Value], failing if any object repeats a key
+    fn try_from(value: DuplicateValue) -> Result<Self, Self::Error> {
+        value.to_value(DuplicatePolicy::Error)
+    }
+}
+
+impl<'de> Deserialize<'de> for DuplicateValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DuplicateValueVisitor)
+    }
+}
+
+struct DuplicateValueVisitor;
+
+impl<'de> Visitor<'de> for DuplicateValueVisitor {
+    type Value = DuplicateValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any valid JSON value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<DuplicateValue, E> {
+        Ok(DuplicateValue::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<DuplicateValue, E> {
+        Ok(DuplicateValue::Number(value.into()))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<DuplicateValue, E> {
+        Ok(DuplicateValue::Number(value.into()))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<DuplicateValue, E> {
+        Ok(Number::from_f64(value).map_or(DuplicateValue::Null, DuplicateValue::Number))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<DuplicateValue, E> {
+        Ok(DuplicateValue::String(value.to_owned()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<DuplicateValue, E> {
+        Ok(DuplicateValue::String(value))
+    }
+
+    fn visit_none<E>(self) -> Result<DuplicateValue, E> {
+        Ok(DuplicateValue::Null)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<DuplicateValue, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_unit<E>(self) -> Result<DuplicateValue, E> {
+        Ok(DuplicateValue::Null)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<DuplicateValue, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::new();
+
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+
+        Ok(DuplicateValue::Array(values))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<DuplicateValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        DuplicateMap::deserialize(serde::de::value::MapAccessDeserializer::new(map)).map(DuplicateValue::Object)
+    }
+}
+
+impl Serialize for DuplicateValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Null => serializer.serialize_unit(),
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::Number(value) => value.serialize(serializer),
+            Self::String(value) => serializer.serialize_str(value),
+            Self::Array(values) => values.serialize(serializer),
+            Self::Object(entries) => entries.serialize(serializer),
+        }
+    }
+}
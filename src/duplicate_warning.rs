@@ -0,0 +1,80 @@
+use std::fmt;
+
+/// # Duplicate Warning
+///
+/// Something done leniently while deserializing a value which would otherwise go unnoticed, see [WithDiagnostics](crate::WithDiagnostics)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DuplicateWarning {
+    /// An occurrence of a duplicated field was thrown away in favour of another
+    DiscardedDuplicate {
+        /// The name of the struct the field belongs to
+        type_name: &'static str,
+
+        /// The name of the field
+        field: &'static str,
+
+        /// The key the discarded occurrence was found under
+        key: String,
+
+        /// The index of the discarded occurrence among every occurrence of the field
+        occurrence: usize,
+    },
+
+    /// An occurrence of a field could not be deserialized and was skipped
+    SkippedInvalidValue {
+        /// The name of the struct the field belongs to
+        type_name: &'static str,
+
+        /// The name of the field
+        field: &'static str,
+
+        /// The key the skipped occurrence was found under
+        key: String,
+
+        /// The index of the skipped occurrence among every occurrence of the field
+        occurrence: usize,
+
+        /// Why the occurrence could not be deserialized
+        error: String,
+    },
+
+    /// No occurrence of a field could be deserialized, so its default value was used
+    DefaultUsed {
+        /// The name of the struct the field belongs to
+        type_name: &'static str,
+
+        /// The name of the field
+        field: &'static str,
+
+        /// Why each occurrence could not be deserialized
+        errors: Vec<String>,
+    },
+}
+
+impl fmt::Display for DuplicateWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DiscardedDuplicate {
+                type_name,
+                field,
+                key,
+                occurrence,
+            } => write!(f, "discarded occurrence {occurrence} of field `{type_name}.{field}` found under `{key}`"),
+            Self::SkippedInvalidValue {
+                type_name,
+                field,
+                key,
+                occurrence,
+                error,
+            } => write!(
+                f,
+                "skipped invalid occurrence {occurrence} of field `{type_name}.{field}` found under `{key}`: {error}"
+            ),
+            Self::DefaultUsed {
+                type_name,
+                field,
+                errors,
+            } => write!(f, "used default value for field `{type_name}.{field}`: {}", errors.join("; ")),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Deref;
+
+use serde::de::{Deserialize, Deserializer, Error, Visitor};
+
+use crate::content::Content;
+
+/// The key of a field, borrowed from the data when possible so structs can also be deserialized from owned data such as
+/// `serde_json::Value`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldKey<'de>(Cow<'de, str>);
+
+impl Deref for FieldKey<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for FieldKey<'_> {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl<'de> From<FieldKey<'de>> for Content<'de> {
+    fn from(value: FieldKey<'de>) -> Self {
+        match value.0 {
+            Cow::Borrowed(key) => Content::Str(key),
+            Cow::Owned(key) => Content::String(key),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldKey<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FieldKeyVisitor)
+    }
+}
+
+struct FieldKeyVisitor;
+
+impl<'de> Visitor<'de> for FieldKeyVisitor {
+    type Value = FieldKey<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a field name")
+    }
+
+    fn visit_borrowed_str<E: Error>(self, value: &'de str) -> Result<Self::Value, E> {
+        Ok(FieldKey(Cow::Borrowed(value)))
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(FieldKey(Cow::Owned(value.to_owned())))
+    }
+
+    fn visit_string<E: Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(FieldKey(Cow::Owned(value)))
+    }
+}
@@ -0,0 +1,459 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{DuplicateKey, TextPosition};
+
+/// # Find Duplicate Keys
+///
+/// Scan a JSON document for objects containing the same key more than once, without deserializing it into any value.
+/// Every duplicated key is returned with the JSON Pointer of its object and the position of each of its occurrences,
+/// ordered by their first occurrence.
+///
+/// ## Usage
+///
+///```rust
+///use serde_deserialize_duplicates::find_duplicate_keys;
+///
+///let data = r#"{
+///    "name": "a",
+///    "items": [{ "id": 1, "id": 2 }],
+///    "name": "b"
+///}"#;
+///
+///let duplicates = find_duplicate_keys(data).unwrap();
+///
+///assert_eq!(duplicates[0].pointer, "");
+///assert_eq!(duplicates[0].key, "name");
+///assert_eq!(duplicates[0].occurrences[1].line, 4);
+///
+///assert_eq!(duplicates[1].pointer, "/items/0");
+///assert_eq!(duplicates[1].key, "id");
+///assert_eq!(duplicates[1].occurrences[1].column, 26);
+///```
+pub fn find_duplicate_keys(json: &str) -> Result<Vec<DuplicateKey>, JsonSyntaxError> {
+    let mut duplicates = Scanner::new(json).scan()?;
+
+    duplicates.sort_by_key(|duplicate| duplicate.occurrences[0]);
+
+    Ok(duplicates)
+}
+
+/// # JSON Syntax Error
+///
+/// The reason a document could not be scanned by [find_duplicate_keys], as it is not valid JSON
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonSyntaxError {
+    /// What was wrong with the document
+    pub message: String,
+
+    /// Where the problem was found
+    pub position: TextPosition,
+}
+
+impl fmt::Display for JsonSyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for JsonSyntaxError {}
+
+/// An object or array which is still being scanned
+enum Container {
+    Object {
+        /// The reference token of the object within its parent, if it has one
+        token: Option<String>,
+
+        /// The position of every occurrence of each key found so far
+        keys: HashMap<String, Vec<TextPosition>>,
+
+        /// The key of the value being scanned
+        current_key: String,
+    },
+    Array {
+        /// The reference token of the array within its parent, if it has one
+        token: Option<String>,
+
+        /// The index of the value being scanned
+        index: usize,
+    },
+}
+
+impl Container {
+    fn token(&self) -> &Option<String> {
+        match self {
+            Self::Object { token, .. } | Self::Array { token, .. } => token,
+        }
+    }
+}
+
+/// A single pass over a JSON document, tracking only the keys of the objects it is within
+struct Scanner<'a> {
+    json: &'a str,
+    offset: usize,
+    line: usize,
+    line_start: usize,
+    /// The offset and column of the last position taken, so columns are counted from there rather than the line start
+    last_column: Cell<(usize, usize)>,
+    containers: Vec<Container>,
+    duplicates: Vec<DuplicateKey>,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(json: &'a str) -> Self {
+        Self {
+            json,
+            offset: 0,
+            line: 1,
+            line_start: 0,
+            last_column: Cell::new((0, 1)),
+            containers: Vec::new(),
+            duplicates: Vec::new(),
+        }
+    }
+
+    fn scan(mut self) -> Result<Vec<DuplicateKey>, JsonSyntaxError> {
+        loop {
+            if self.scan_value()? && self.scan_container_ends()? {
+                return Ok(self.duplicates);
+            }
+        }
+    }
+
+    /// Scan a value, stopping after the opening of a non empty container, returning whether the value is complete
+    fn scan_value(&mut self) -> Result<bool, JsonSyntaxError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(b'{') => {
+                self.offset += 1;
+
+                self.containers.push(Container::Object {
+                    token: self.value_token(),
+                    keys: HashMap::new(),
+                    current_key: String::new(),
+                });
+
+                self.skip_whitespace();
+
+                if self.peek() == Some(b'}') {
+                    self.offset += 1;
+                    self.close_container();
+                } else {
+                    self.scan_key()?;
+
+                    return Ok(false);
+                }
+            }
+            Some(b'[') => {
+                self.offset += 1;
+
+                self.containers.push(Container::Array {
+                    token: self.value_token(),
+                    index: 0,
+                });
+
+                self.skip_whitespace();
+
+                if self.peek() == Some(b']') {
+                    self.offset += 1;
+                    self.close_container();
+                } else {
+                    return Ok(false);
+                }
+            }
+            Some(b'"') => {
+                self.scan_string()?;
+            }
+            Some(b't') => self.scan_literal("true")?,
+            Some(b'f') => self.scan_literal("false")?,
+            Some(b'n') => self.scan_literal("null")?,
+            Some(b'-' | b'0'..=b'9') => self.scan_number()?,
+            Some(_) => return Err(self.error("expected value")),
+            None => return Err(self.error("unexpected end of input")),
+        }
+
+        Ok(true)
+    }
+
+    /// Scan the separators and closing brackets following a value, returning whether the document is complete
+    fn scan_container_ends(&mut self) -> Result<bool, JsonSyntaxError> {
+        loop {
+            self.skip_whitespace();
+
+            let next = self.peek();
+
+            match self.containers.last_mut() {
+                None if next.is_none() => return Ok(true),
+                None => return Err(self.error("trailing characters")),
+                Some(Container::Object { .. }) => match next {
+                    Some(b',') => {
+                        self.offset += 1;
+                        self.skip_whitespace();
+
+                        return self.scan_key().map(|_| false);
+                    }
+                    Some(b'}') => {
+                        self.offset += 1;
+                        self.close_container();
+                    }
+                    _ => return Err(self.error("expected `,` or `}`")),
+                },
+                Some(Container::Array { index, .. }) => match next {
+                    Some(b',') => {
+                        self.offset += 1;
+                        *index += 1;
+
+                        return Ok(false);
+                    }
+                    Some(b']') => {
+                        self.offset += 1;
+                        self.close_container();
+                    }
+                    _ => return Err(self.error("expected `,` or `]`")),
+                },
+            }
+        }
+    }
+
+    /// Scan an object's key and the colon following it, recording where it was found
+    fn scan_key(&mut self) -> Result<(), JsonSyntaxError> {
+        if self.peek() != Some(b'"') {
+            return Err(self.error("expected key"));
+        }
+
+        let position = self.position();
+
+        let key = self.scan_string()?;
+
+        self.skip_whitespace();
+
+        if self.peek() != Some(b':') {
+            return Err(self.error("expected `:`"));
+        }
+
+        self.offset += 1;
+
+        if let Some(Container::Object { keys, current_key, .. }) = self.containers.last_mut() {
+            keys.entry(key.clone()).or_default().push(position);
+
+            *current_key = key;
+        }
+
+        Ok(())
+    }
+
+    /// Finish the innermost container, recording every key its object repeated
+    fn close_container(&mut self) {
+        if let Some(Container::Object { token, keys, .. }) = self.containers.pop() {
+            if keys.values().all(|occurrences| occurrences.len() == 1) {
+                return;
+            }
+
+            let pointer = self
+                .containers
+                .iter()
+                .map(Container::token)
+                .chain([&token])
+                .flatten()
+                .fold(String::new(), |pointer, token| pointer + "/" + token);
+
+            self.duplicates.extend(
+                keys.into_iter()
+                    .filter(|(_, occurrences)| occurrences.len() > 1)
+                    .map(|(key, occurrences)| DuplicateKey {
+                        pointer: pointer.clone(),
+                        key,
+                        occurrences,
+                    }),
+            );
+        }
+    }
+
+    /// The reference token of the value about to be scanned within its container, escaped for use in a JSON Pointer
+    fn value_token(&self) -> Option<String> {
+        match self.containers.last()? {
+            Container::Object { current_key, .. } => Some(current_key.replace('~', "~0").replace('/', "~1")),
+            Container::Array { index, .. } => Some(index.to_string()),
+        }
+    }
+
+    fn scan_string(&mut self) -> Result<String, JsonSyntaxError> {
+        self.offset += 1;
+
+        let mut value = String::new();
+
+        loop {
+            let remaining = &self.json[self.offset..];
+
+            let Some(end) = remaining.find(|c: char| c == '"' || c == '\\' || c < ' ') else {
+                self.offset = self.json.len();
+
+                return Err(self.error("unterminated string"));
+            };
+
+            value.push_str(&remaining[..end]);
+
+            self.offset += end;
+
+            match self.peek() {
+                Some(b'"') => {
+                    self.offset += 1;
+
+                    return Ok(value);
+                }
+                Some(b'\\') => {
+                    self.offset += 1;
+
+                    self.scan_escape(&mut value)?;
+                }
+                _ => return Err(self.error("control character in string")),
+            }
+        }
+    }
+
+    fn scan_escape(&mut self, value: &mut String) -> Result<(), JsonSyntaxError> {
+        let escaped = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.offset += 1;
+
+                let mut units = vec![self.scan_hex()?];
+
+                // A high surrogate is expected to be followed by the escaped low surrogate completing it
+                if (0xD800..0xDC00).contains(&units[0]) && self.json[self.offset..].starts_with("\\u") {
+                    self.offset += 2;
+
+                    units.push(self.scan_hex()?);
+                }
+
+                value.extend(char::decode_utf16(units).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)));
+
+                return Ok(());
+            }
+            _ => return Err(self.error("invalid escape")),
+        };
+
+        self.offset += 1;
+
+        value.push(escaped);
+
+        Ok(())
+    }
+
+    fn scan_hex(&mut self) -> Result<u16, JsonSyntaxError> {
+        let digits = self
+            .json
+            .get(self.offset..self.offset + 4)
+            .filter(|digits| digits.bytes().all(|digit| digit.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+
+        self.offset += 4;
+
+        Ok(u16::from_str_radix(digits, 16).expect("hex digits were checked"))
+    }
+
+    fn scan_literal(&mut self, literal: &str) -> Result<(), JsonSyntaxError> {
+        if !self.json[self.offset..].starts_with(literal) {
+            return Err(self.error("expected value"));
+        }
+
+        self.offset += literal.len();
+
+        Ok(())
+    }
+
+    fn scan_number(&mut self) -> Result<(), JsonSyntaxError> {
+        if self.peek() == Some(b'-') {
+            self.offset += 1;
+        }
+
+        match self.peek() {
+            Some(b'0') => self.offset += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return Err(self.error("invalid number")),
+        }
+
+        if self.peek() == Some(b'.') {
+            self.offset += 1;
+
+            self.scan_digits()?;
+        }
+
+        if let Some(b'e' | b'E') = self.peek() {
+            self.offset += 1;
+
+            if let Some(b'+' | b'-') = self.peek() {
+                self.offset += 1;
+            }
+
+            self.scan_digits()?;
+        }
+
+        Ok(())
+    }
+
+    /// Scan at least one digit
+    fn scan_digits(&mut self) -> Result<(), JsonSyntaxError> {
+        if !self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            return Err(self.error("invalid number"));
+        }
+
+        self.skip_digits();
+
+        Ok(())
+    }
+
+    fn skip_digits(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.offset += 1;
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(byte @ (b' ' | b'\t' | b'\n' | b'\r')) = self.peek() {
+            self.offset += 1;
+
+            if byte == b'\n' {
+                self.line += 1;
+                self.line_start = self.offset;
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.json.as_bytes().get(self.offset).copied()
+    }
+
+    fn position(&self) -> TextPosition {
+        let (counted_offset, counted_column) = match self.last_column.get() {
+            (offset, column) if offset >= self.line_start => (offset, column),
+            _ => (self.line_start, 1),
+        };
+
+        let column = counted_column + self.json[counted_offset..self.offset].chars().count();
+
+        self.last_column.set((self.offset, column));
+
+        TextPosition {
+            offset: self.offset,
+            line: self.line,
+            column,
+        }
+    }
+
+    fn error(&self, message: &str) -> JsonSyntaxError {
+        JsonSyntaxError {
+            message: message.to_owned(),
+            position: self.position(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+/// # Key Order
+///
+/// The key of every entry of a map, in the order they were found, populating a field annotated with
+/// `#[duplicates(key_order)]`. [SerializeDuplicates](crate::SerializeDuplicates) replays it so a struct is written back
+/// with the same keys, in the same order, as it was read from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyOrder {
+    /// Every entry, in the order it was found
+    pub entries: Vec<KeyOrderEntry>,
+}
+
+impl KeyOrder {
+    /// Were no entries found
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over every entry
+    pub fn iter(&self) -> std::slice::Iter<'_, KeyOrderEntry> {
+        self.entries.iter()
+    }
+
+    /// The position of the entry a field's value is written at: its selected occurrence, or its first when occurrences
+    /// were combined or none was used as is
+    pub fn value_position(&self, field: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.field == Some(field) && entry.selected)
+            .or_else(|| self.entries.iter().position(|entry| entry.field == Some(field)))
+    }
+}
+
+impl<'a> IntoIterator for &'a KeyOrder {
+    type Item = &'a KeyOrderEntry;
+    type IntoIter = std::slice::Iter<'a, KeyOrderEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// # Key Order Entry
+///
+/// A single entry of a map, see [KeyOrder]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyOrderEntry {
+    /// The key the entry was found under
+    pub key: String,
+
+    /// The name of the field the entry is an occurrence of, or [None] if its key matched no field
+    pub field: Option<&'static str>,
+
+    /// Was this the occurrence used as the field's value
+    pub selected: bool,
+}
@@ -92,128 +92,591 @@
 //!     occurs_more_than_once: Option<String>
 //!}
 //! ```
+//!
+//! ### Skipping invalid duplicates
+//!
+//! Sometimes one of the duplicates is of the wrong type, for example:
+//!
+//! ```json
+//!{
+//!     "count": "n/a",
+//!     "count": 5
+//!}
+//! ```
+//!
+//! Annotating a field with `#[duplicates(skip_invalid)]` buffers every occurrence of it and uses the first (or last) one
+//! which deserializes successfully, only failing if none of them do.
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+//!
+//!#[derive(DeserializeFirstDuplicate)]
+//!struct Counter {
+//!    #[duplicates(skip_invalid)]
+//!     count: i32
+//!}
+//!
+//!let counter: Counter = serde_json::from_str(r#"{ "count": "n/a", "count": 5 }"#).unwrap();
+//!
+//!assert_eq!(counter.count, 5);
+//! ```
+//!
+//! ### Defaulting malformed values
+//!
+//! For best-effort ingestion, `#[duplicates(default_on_error)]` uses the field's [Default] value when the selected occurrence is
+//! malformed instead of failing deserialization. Combined with `skip_invalid`, the default is only used once every occurrence has failed.
+//!
+//! A field annotated with `#[duplicates(warnings)]`, of any type collecting [String]s, is never read from the data. It is instead populated
+//! with a message for every occurrence skipped and every default used while deserializing the other fields.
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::DeserializeLastDuplicate;
+//!
+//!#[derive(DeserializeLastDuplicate)]
+//!struct Reading {
+//!    #[duplicates(default_on_error)]
+//!     value: f64,
+//!
+//!    #[duplicates(warnings)]
+//!     warnings: Vec<String>
+//!}
+//!
+//!let reading: Reading = serde_json::from_str(r#"{ "value": 1.5, "value": "broken" }"#).unwrap();
+//!
+//!assert_eq!(reading.value, 0.0);
+//!assert_eq!(reading.warnings.len(), 1);
+//! ```
+//!
+//! ### Merging duplicated objects
+//!
+//! When a key repeats with object values, `#[duplicates(merge)]` deep-merges every occurrence before deserializing the field,
+//! following JSON Merge Patch semantics: later keys override earlier ones recursively, and `null` removes a key.
+//!
+//! ```rust
+//!use std::collections::BTreeMap;
+//!use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+//!
+//!#[derive(DeserializeFirstDuplicate)]
+//!struct Document {
+//!    #[duplicates(merge)]
+//!     meta: BTreeMap<String, i32>
+//!}
+//!
+//!let document: Document = serde_json::from_str(r#"{ "meta": { "a": 1 }, "meta": { "b": 2 } }"#).unwrap();
+//!
+//!assert_eq!(document.meta, BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]));
+//! ```
+//!
+//! ### Extending duplicated collections
+//!
+//! For collection fields such as [Vec], [HashSet](std::collections::HashSet) or [BTreeMap](std::collections::BTreeMap),
+//! `#[duplicates(extend)]` extends the collection with every occurrence rather than replacing it.
+//! Any collection implementing [Extend] over its own items can be used.
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::DeserializeLastDuplicate;
+//!
+//!#[derive(DeserializeLastDuplicate)]
+//!struct Order {
+//!    #[duplicates(extend)]
+//!     items: Vec<i32>
+//!}
+//!
+//!let order: Order = serde_json::from_str(r#"{ "items": [1, 2], "items": [3] }"#).unwrap();
+//!
+//!assert_eq!(order.items, vec![1, 2, 3]);
+//! ```
+//!
+//! ### Resolving duplicates with a custom function
+//!
+//! When neither the first nor the last occurrence is the right one, `#[duplicates(resolve = "path::to::function")]` hands every
+//! occurrence to a function of your own. It receives each deserialized occurrence paired with the key it was found under, in the
+//! order they were found, and returns the chosen value or an error implementing [Display](std::fmt::Display).
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+//!
+//!fn highest_version(occurrences: Vec<(&str, u32)>) -> Result<u32, String> {
+//!    occurrences
+//!        .into_iter()
+//!        .map(|(_, version)| version)
+//!        .max()
+//!        .ok_or_else(|| "no version found".to_owned())
+//!}
+//!
+//!#[derive(DeserializeFirstDuplicate)]
+//!struct Package {
+//!    #[duplicates(resolve = "highest_version")]
+//!     version: u32
+//!}
+//!
+//!let package: Package = serde_json::from_str(r#"{ "version": 2, "version": 7, "version": 3 }"#).unwrap();
+//!
+//!assert_eq!(package.version, 7);
+//! ```
+//!
+//! ### Aggregating numeric duplicates
+//!
+//! For metrics where a counter is repeated per shard, `#[duplicates(sum)]`, `#[duplicates(min)]` and `#[duplicates(max)]`
+//! aggregate every occurrence. `sum` requires the field type to implement [Add](std::ops::Add), and types which also
+//! implement [CheckedAdd], such as every primitive integer, fail deserialization rather than overflowing. `min` and `max`
+//! require [PartialOrd], so floating point fields are supported too.
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+//!
+//!#[derive(DeserializeFirstDuplicate)]
+//!struct Metrics {
+//!    #[duplicates(sum)]
+//!     requests: u64,
+//!
+//!    #[duplicates(max)]
+//!     latency: f64
+//!}
+//!
+//!let metrics: Metrics = serde_json::from_str(
+//!    r#"{ "requests": 3, "latency": 0.5, "requests": 4, "latency": 1.5 }"#
+//!).unwrap();
+//!
+//!assert_eq!(metrics.requests, 7);
+//!assert_eq!(metrics.latency, 1.5);
+//! ```
+//!
+//! ### Majority vote
+//!
+//! For sources repeating a reading for redundancy, `#[duplicates(majority)]` uses the most frequent occurrence, comparing them with [PartialEq].
+//! Ties are settled by using the tied value seen first by default, or configured with `majority = "first"`, `majority = "last"` or `majority = "error"`.
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+//!
+//!#[derive(DeserializeFirstDuplicate)]
+//!struct Sensor {
+//!    #[duplicates(majority = "error")]
+//!     temperature: f64
+//!}
+//!
+//!let sensor: Sensor = serde_json::from_str(
+//!    r#"{ "temperature": 21.5, "temperature": 80.0, "temperature": 21.5 }"#
+//!).unwrap();
+//!
+//!assert_eq!(sensor.temperature, 21.5);
+//! ```
+//!
+//! ### Selecting a specific occurrence
+//!
+//! `#[duplicates(nth = 2)]` uses the second occurrence of a field, while negative positions count back from the end, so
+//! `nth = -1` is the last occurrence. Fields with fewer occurrences than the position are treated as missing. Only the
+//! selected occurrence is deserialized, so the others need not be of the field's type. Combined with `skip_invalid`,
+//! only occurrences which deserialize successfully are counted.
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+//!
+//!#[derive(DeserializeFirstDuplicate)]
+//!struct Envelope {
+//!    #[duplicates(nth = 2)]
+//!     body: String
+//!}
+//!
+//!let envelope: Envelope = serde_json::from_str(r#"{ "body": "placeholder", "body": "real" }"#).unwrap();
+//!
+//!assert_eq!(envelope.body, "real".to_owned());
+//! ```
+//!
+//! ### Types from other crates
+//!
+//! Types which cannot be annotated, such as those from other crates using serde's own derive, can be wrapped in
+//! [FirstDuplicate] or [LastDuplicate] to deserialize them using the first or last occurrence of every duplicated key found
+//! in any map within them.
+//!
+//! ```rust
+//!use serde::Deserialize;
+//!use serde_deserialize_duplicates::LastDuplicate;
+//!
+//!#[derive(Deserialize)]
+//!struct Dog {
+//!    pub breed: String
+//!}
+//!
+//!let LastDuplicate(dog): LastDuplicate<Dog> = serde_json::from_str(r#"{ "breed": "Labrador", "breed": "Retriever" }"#).unwrap();
+//!
+//!assert_eq!(dog.breed, "Retriever".to_owned());
+//! ```
+//!
+//! For other policies, or to deserialize through a seed, any deserializer can be wrapped in a [DeduplicatingDeserializer]
+//! removing the duplicate keys of every map in its input following a [DuplicatePolicy].
+//!
+//! ### Duplicate keys within maps
+//!
+//! Unlike structs, maps such as [HashMap](std::collections::HashMap) silently keep the last value of a repeated key. A map
+//! field annotated with `#[duplicates(map_keys = "...")]` instead follows the named policy, one of `first`, `last`, `error`
+//! or `collect`, for the keys within it. Under `collect` every key holds a sequence of its values, so the map's values
+//! should be collections such as [Vec]. Maps outside of derived structs can be wrapped in [NoDuplicateKeys] to reject
+//! repeated keys.
+//!
+//! ```rust
+//!use std::collections::HashMap;
+//!
+//!use serde::Serialize;
+//!use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+//!
+//!#[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+//!struct Sensor {
+//!    #[duplicates(map_keys = "error")]
+//!    pub labels: HashMap<String, String>
+//!}
+//!
+//!let result = serde_json::from_str::<Sensor>(r#"{ "labels": { "unit": "C", "unit": "F" } }"#);
+//!
+//!assert!(result.is_err());
+//! ```
+//!
+//! ### Keeping every entry
+//!
+//! A [DuplicateMap] keeps every entry of a map in order, repeated keys included, with lookups for the first, last or every
+//! value of a key. A field annotated with `#[duplicates(rest)]` is populated with every entry whose key matches no other
+//! field, rather than those entries being discarded.
+//!
+//! ```rust
+//!use serde::Serialize;
+//!use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DuplicateMap};
+//!
+//!#[derive(Serialize, DeserializeFirstDuplicate)]
+//!struct Request {
+//!    pub path: String,
+//!
+//!    #[duplicates(rest)]
+//!    pub headers: DuplicateMap<String, String>
+//!}
+//!
+//!let request: Request = serde_json::from_str(r#"{ "path": "/", "cookie": "a=1", "cookie": "b=2" }"#).unwrap();
+//!
+//!assert_eq!(request.headers.get_all("cookie").count(), 2);
+//! ```
+//!
+//! A field annotated with `#[duplicates(extra)]` is populated the same way, but with the struct's duplicate policy applied
+//! to the entries, so any map type, such as a [BTreeMap](std::collections::BTreeMap) of `serde_json::Value`, can hold them.
+//! Such fields may also be marked `#[serde(flatten)]`, so unknown keys are passed through when serialized again.
+//!
+//! ```rust
+//!use std::collections::BTreeMap;
+//!
+//!use serde::Serialize;
+//!use serde_deserialize_duplicates::DeserializeLastDuplicate;
+//!
+//!#[derive(Serialize, DeserializeLastDuplicate)]
+//!struct Request {
+//!    pub path: String,
+//!
+//!    #[duplicates(extra)]
+//!    #[serde(flatten)]
+//!    pub extras: BTreeMap<String, serde_json::Value>
+//!}
+//!
+//!let request: Request = serde_json::from_str(r#"{ "path": "/", "trace": 1, "trace": 2 }"#).unwrap();
+//!
+//!assert_eq!(serde_json::to_string(&request).unwrap(), r#"{"path":"/","trace":2}"#);
+//! ```
+//!
+//! ### Keeping every entry of a whole document
+//!
+//! A [DuplicateValue](crate::DuplicateValue) mirrors `serde_json::Value`, but its objects keep every entry in order, so
+//! problematic documents can be inspected, logged and replayed faithfully. It can be converted into a `serde_json::Value`
+//! under any [DuplicatePolicy], or deserialized into any type, including the derived structs of this crate.
+//!
+//! ### Serializing duplicates again
+//!
+//! A field annotated with `#[duplicates(collect)]` keeps every occurrence, in order, as a sequence such as a [Vec]. Deriving
+//! [SerializeDuplicates] in place of `Serialize` writes each of them back under the field's name, along with every entry
+//! of a `rest` or `extra` field, so proxied documents keep their repeated keys on the way out.
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DuplicateMap, SerializeDuplicates};
+//!
+//!#[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+//!struct Request {
+//!    pub path: String,
+//!
+//!    #[duplicates(collect)]
+//!    pub cookie: Vec<String>,
+//!
+//!    #[duplicates(rest)]
+//!    pub headers: DuplicateMap<String, String>
+//!}
+//!
+//!let data = r#"{"path":"/","cookie":"a=1","cookie":"b=2","accept":"*/*","accept":"text/html"}"#;
+//!
+//!let request: Request = serde_json::from_str(data).unwrap();
+//!
+//!assert_eq!(serde_json::to_string(&request).unwrap(), data);
+//! ```
+//!
+//! Fields are written in the order they are declared, so keys of different fields which were interleaved in the input are
+//! grouped by field. Adding a field annotated with `#[duplicates(key_order)]`, of type [KeyOrder], records the key of
+//! every entry in the order it was found, and entries are then written back under the same keys, in the same order.
+//! A field holding a single value is written under the key of its selected occurrence, at its position.
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::{DeserializeLastDuplicate, KeyOrder, SerializeDuplicates};
+//!
+//!#[derive(SerializeDuplicates, DeserializeLastDuplicate)]
+//!struct Request {
+//!    #[serde(alias = "uri")]
+//!    pub path: String,
+//!
+//!    #[duplicates(collect)]
+//!    pub cookie: Vec<String>,
+//!
+//!    #[duplicates(key_order)]
+//!    pub order: KeyOrder
+//!}
+//!
+//!let data = r#"{"cookie":"a=1","uri":"/","cookie":"b=2"}"#;
+//!
+//!let request: Request = serde_json::from_str(data).unwrap();
+//!
+//!assert_eq!(serde_json::to_string(&request).unwrap(), data);
+//! ```
+//!
+//! ### Keeping raw occurrences
+//!
+//! A field annotated with `#[duplicates(raw)]` keeps every occurrence exactly as it was received, unparsed, in a collection
+//! of `Box<serde_json::value::RawValue>` such as a [Vec]. The attribute requires the `serde_json` feature, without which
+//! deriving fails with an error naming it. Raw values can only be captured when deserializing directly from JSON text,
+//! and [SerializeDuplicates] writes each of them back untouched.
+#![cfg_attr(feature = "serde_json", doc = r##"
+```rust
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, SerializeDuplicates};
+use serde_json::value::RawValue;
 
-#![warn(missing_docs)]
+#[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+struct Payment {
+    pub id: u32,
 
-use aliased_field::AliasedFields;
-use quote::{quote, quote_spanned};
-use syn::{parse_macro_input, DeriveInput};
+    #[duplicates(raw)]
+    pub amount: Vec<Box<RawValue>>
+}
 
-mod aliased_field;
-mod generate_deserialization_impl;
-mod parse_fields;
+let data = r#"{"id":1,"amount":10.50,"amount":"10.5"}"#;
 
-use generate_deserialization_impl::generate_deserialization_impl;
-use parse_fields::parse_fields;
+let payment: Payment = serde_json::from_str(data).unwrap();
 
-/// # Deserialize First Duplicate macro
-///
-/// Macro which creates a deserializer for a struct with named values which takes the first matching value it finds, ignoring the rest.
-///
-/// ## Usage
-///
-///```rust
-///use serde_deserialize_duplicates::DeserializeFirstDuplicate;
-///use serde::Serialize;
-///
-///// Target struct
-///#[derive(Serialize, DeserializeFirstDuplicate)]
-///struct ValueHolder {
-///    pub value: String
-///}
-///
-///// Mock data with duplicate values
-///let data = r#"
-///     {
-///         "value": "first",
-///         "value": "second"
-///     }"#;
-///
-///// Deserialize our data into a ValueHolder, taking the first value we find
-///let holder: ValueHolder = serde_json::from_str(data).unwrap();
-///
-///assert_eq!(holder.value, "first".to_owned());
-///```
-#[proc_macro_derive(DeserializeFirstDuplicate)]
-pub fn deserialize_first_duplicate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
+assert_eq!(payment.amount.iter().map(|amount| amount.get()).collect::<Vec<_>>(), vec!["10.50", r#""10.5""#]);
+assert_eq!(serde_json::to_string(&payment).unwrap(), data);
+```
+"##)]
+//!
+//! ### Reporting duplicates
+//!
+//! To observe how often duplicates are received, [deserialize_with_report] returns a [DuplicateReport] alongside the value,
+//! listing every duplicated field, the key each of its occurrences was found under and which occurrence was used.
+//!
+//! ### Finding duplicate keys in JSON documents
+//!
+//! To check fixture files or captured payloads before they are deserialized, [find_duplicate_keys] scans JSON text in a
+//! single pass, without building any value, and returns a [DuplicateKey] for every key repeated within an object, with
+//! the JSON Pointer of the object and the byte offset, line and column of each occurrence.
+//!
+//! ### Choosing a policy at runtime
+//!
+//! A struct derived once can be deserialized under a [DuplicatePolicy] chosen at runtime, such as per tenant, by deserializing
+//! through the [WithPolicy] seed. The policy replaces the derive's own for every field without a strategy of its own, in the
+//! struct and any nested within it. Besides taking the first or last occurrence, it may reject duplicates outright or collect
+//! every occurrence of a field into a sequence, see [DuplicatePolicy::Collect] for how fields found once are handled.
+//!
+//! ```rust
+//!use serde::de::DeserializeSeed;
+//!use serde::Serialize;
+//!use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DuplicatePolicy, WithPolicy};
+//!
+//!#[derive(Serialize, DeserializeFirstDuplicate)]
+//!struct Post {
+//!    pub tags: Vec<String>
+//!}
+//!
+//!let mut deserializer = serde_json::Deserializer::from_str(r#"{ "tags": "rust", "tags": "serde" }"#);
+//!
+//!let post: Post = WithPolicy::new(DuplicatePolicy::Collect).deserialize(&mut deserializer).unwrap();
+//!
+//!assert_eq!(post.tags, vec!["rust".to_owned(), "serde".to_owned()]);
+//! ```
+//!
+//! ### Setting a strategy for a whole struct
+//!
+//! A struct annotated with `#[duplicates(strategy = "...")]` follows that policy, one of `first`, `last`, `error` or `collect`,
+//! in place of its derive's. Adding `recursive` has every struct nested within it follow the same policy too, so a single
+//! attribute on the outermost struct governs the whole tree, except for nested structs with a strategy of their own, which
+//! keep it and propagate it in turn if they are `recursive` too. A policy chosen at runtime still takes precedence over
+//! every strategy set on a struct.
+//!
+//! ```rust
+//!use serde::Serialize;
+//!use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+//!
+//!#[derive(Serialize, DeserializeFirstDuplicate)]
+//!struct Owner {
+//!    pub name: String
+//!}
+//!
+//!#[derive(Serialize, DeserializeFirstDuplicate)]
+//!#[duplicates(strategy = "last", recursive)]
+//!struct Dog {
+//!    pub owner: Owner
+//!}
+//!
+//!let dog: Dog = serde_json::from_str(r#"{ "owner": { "name": "Alice", "name": "Bob" } }"#).unwrap();
+//!
+//!assert_eq!(dog.owner.name, "Bob".to_owned());
+//! ```
+//!
+//! ### Collecting lenient-mode warnings
+//!
+//! Discarded duplicates, skipped invalid occurrences and defaults used in place of malformed values can be returned to the caller
+//! by deserializing through the [WithDiagnostics] seed, which adds a [DuplicateWarning] for each of them to a collector, including
+//! those raised by nested structs.
+//!
+//! ### Recording which alias was used
+//!
+//! When migrating an API through aliases, a field annotated with `#[duplicates(source_of = "field")]` is populated with the key
+//! the named field's value was taken from rather than being read from the data. It may be a [String], left empty when no single
+//! occurrence was used, or an [Option] of one.
+//!
+//! ```rust
+//!use serde::Serialize;
+//!use serde_deserialize_duplicates::DeserializeFirstDuplicate;
+//!
+//!#[derive(Serialize, DeserializeFirstDuplicate)]
+//!struct Dog {
+//!    #[serde(alias = "type")]
+//!     breed: String,
+//!
+//!    #[duplicates(source_of = "breed")]
+//!     breed_key: String
+//!}
+//!
+//!let dog: Dog = serde_json::from_str(r#"{ "type": "Labrador" }"#).unwrap();
+//!
+//!assert_eq!(dog.breed_key, "type".to_owned());
+//! ```
+//!
+//! ### Counting occurrences
+//!
+//! Similarly, a field annotated with `#[duplicates(count_of = "field")]` is populated with how many times the named field
+//! appeared under any of its names, so suspicious duplication can be flagged without parsing the data again.
+//!
+//! ```rust
+//!use serde_deserialize_duplicates::DeserializeLastDuplicate;
+//!
+//!#[derive(DeserializeLastDuplicate)]
+//!struct Record {
+//!     value: i32,
+//!
+//!    #[duplicates(count_of = "value")]
+//!     value_count: usize
+//!}
+//!
+//!let record: Record = serde_json::from_str(r#"{ "value": 1, "value": 2 }"#).unwrap();
+//!
+//!assert_eq!(record.value_count, 2);
+//! ```
+//!
+//! ## Feature flags
+//!
+//! - `tracing`: emit a [tracing](https://docs.rs/tracing) debug event, with the struct name, field, key and occurrence index,
+//!   whenever an occurrence of a duplicated field is discarded by the `first`, `last` or `nth` strategies, and a warn event
+//!   whenever an invalid occurrence is skipped or a default value is used.
+//! - `serde_json` (default): provide [DuplicateValue](crate::DuplicateValue), the `raw` field attribute and the
+//!   `dedup-json` command-line tool, built on `serde_json`.
+//!
+//! ## Command-line tool
+//!
+//! `dedup-json` reads JSON documents from files or standard input, reports every repeated key found by
+//! [find_duplicate_keys] and writes each document back out keeping the first, last, merged or collected values of every
+//! repeated key. Values are merged exactly as `#[duplicates(merge)]` merges them, see
+//! [DuplicateValue::to_merged_value](crate::DuplicateValue::to_merged_value).
+//! With `--check` it only reports duplicates, exiting with a non-zero status if any are found.
+//!
+//! ```text
+//! $ echo '{"a": 1, "a": 2}' | dedup-json --policy first
+//! <stdin>: key `a` repeated 2 times in the root object at line 1 column 2, line 1 column 10
+//! {"a":1}
+//! ```
 
-    match parse_fields(input.data) {
-        Ok(AliasedFields {
-            field_identifiers,
-            names_list,
-            uses_default_selections,
-        }) => generate_deserialization_impl(
-            quote! {
-                #(#( #names_list )|* if #field_identifiers.is_none() => #field_identifiers = Some(map.next_value()?)),*
-            },
-            input.ident,
-            field_identifiers,
-            uses_default_selections,
-        ),
-        Err(e) => {
-            let emitted_error = e.to_string();
+#![warn(missing_docs)]
 
-            quote_spanned! {
-                input.ident.span() =>
-                compile_error!(#emitted_error);
-            }.into()
-        }
-    }
-}
+mod checked_add;
+mod content;
+mod context;
+mod deduplicating_deserializer;
+mod deserialize_with_report;
+mod diagnostics;
+mod duplicate_adapters;
+mod duplicate_key;
+mod duplicate_map;
+mod duplicate_policy;
+mod duplicate_report;
+mod duplicate_warning;
+#[cfg(feature = "serde_json")]
+mod duplicate_value;
+mod field_key;
+mod find_duplicate_keys;
+mod key_order;
+mod no_duplicate_keys;
+mod source_key;
+mod with_diagnostics;
+mod with_policy;
 
-/// # Deserialize Last Duplicate macro
-///
-/// Macro which creates a deserializer for a struct with named values which takes the last matching value it finds.
-///
-/// ## Usage
-///
-///```rust
-///use serde_deserialize_duplicates::DeserializeLastDuplicate;
-///use serde::Serialize;
-///
-///// Target struct
-///#[derive(Serialize, DeserializeLastDuplicate)]
-///struct ValueHolder {
-///    pub value: String
-///}
-///
-///// Mock data with duplicate values
-///let data = r#"
-///     {
-///         "value": "first",
-///         "value": "second"
-///     }"#;
-///
-///// Deserialize our data into a ValueHolder, taking the first value we find
-///let holder: ValueHolder = serde_json::from_str(data).unwrap();
-///
-///assert_eq!(holder.value, "second".to_owned());
-///```
-#[proc_macro_derive(DeserializeLastDuplicate)]
-pub fn deserialize_last_duplicate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
+pub use checked_add::CheckedAdd;
+pub use deduplicating_deserializer::{deserialize_deduplicated, DeduplicatingDeserializer};
+pub use deserialize_with_report::deserialize_with_report;
+pub use duplicate_adapters::{FirstDuplicate, LastDuplicate};
+pub use duplicate_key::{DuplicateKey, TextPosition};
+pub use duplicate_map::DuplicateMap;
+pub use duplicate_policy::DuplicatePolicy;
+pub use duplicate_report::{DuplicateReport, DuplicatedField};
+pub use duplicate_warning::DuplicateWarning;
+#[cfg(feature = "serde_json")]
+pub use duplicate_value::DuplicateValue;
+pub use find_duplicate_keys::{find_duplicate_keys, JsonSyntaxError};
+pub use key_order::{KeyOrder, KeyOrderEntry};
+pub use no_duplicate_keys::NoDuplicateKeys;
+pub use serde_deserialize_duplicates_derive::{DeserializeFirstDuplicate, DeserializeLastDuplicate, SerializeDuplicates};
+pub use with_diagnostics::WithDiagnostics;
+pub use with_policy::WithPolicy;
 
-    match parse_fields(input.data) {
-        Ok(AliasedFields {
-            field_identifiers,
-            names_list,
-            uses_default_selections,
-        }) => generate_deserialization_impl(
-            quote! {
-                #(#( #names_list )|* => #field_identifiers = Some(map.next_value()?)),*
-            },
-            input.ident,
-            field_identifiers,
-            uses_default_selections,
-        ),
-        Err(e) => {
-            let emitted_error = e.to_string();
+/// Fail to compile with a message naming the `serde_json` feature when a derive attribute needing it is used without it
+#[cfg(feature = "serde_json")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_serde_json_feature {
+    ($attribute:literal) => {};
+}
+
+/// Fail to compile with a message naming the `serde_json` feature when a derive attribute needing it is used without it
+#[cfg(not(feature = "serde_json"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_serde_json_feature {
+    ($attribute:literal) => {
+        compile_error!(concat!(
+            "#[duplicates(",
+            $attribute,
+            ")] requires the `serde_json` feature of serde-deserialize-duplicates"
+        ));
+    };
+}
 
-            quote_spanned! {
-                input.ident.span() =>
-                compile_error!(#emitted_error);
-            }.into()
-        }
-    }
+/// Items used by the code generated by this crate's macros, not part of its public API
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::checked_add::{CheckedSummation, Summation, UncheckedSummation};
+    pub use crate::content::{deduplicate, deserialize_single_occurrence, merge, Content, ContentDeserializer};
+    pub use crate::context::{current_policy, record_duplicate, runtime_policy, PolicyScope};
+    pub use crate::diagnostics::{discard_duplicate, skip_invalid_value, use_default};
+    pub use crate::field_key::FieldKey;
+    pub use crate::no_duplicate_keys::MapKeys;
+    pub use crate::source_key::SourceKey;
+    #[cfg(feature = "serde_json")]
+    pub use serde_json::value::RawValue;
 }
@@ -0,0 +1,129 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::content::{collect_entries, deduplicate, Content, ContentDeserializer};
+use crate::DuplicatePolicy;
+
+/// # Map Keys
+///
+/// A [DeserializeSeed] deserializing a map-like `M` after removing its duplicate keys following a [DuplicatePolicy].
+/// Only the keys of the map itself are affected, every value is handed to `M` exactly as it was found. Under
+/// [DuplicatePolicy::Collect] every key holds a sequence of its values, even if it occurred once, so `M` can be a map of
+/// collections such as `HashMap<String, Vec<T>>`.
+pub struct MapKeys<M> {
+    policy: DuplicatePolicy,
+    map: PhantomData<fn() -> M>,
+}
+
+impl<M> MapKeys<M> {
+    /// Deserialize an `M` following the given policy for its keys
+    pub fn new(policy: DuplicatePolicy) -> Self {
+        Self {
+            policy,
+            map: PhantomData,
+        }
+    }
+}
+
+impl<'de, M: Deserialize<'de>> DeserializeSeed<'de> for MapKeys<M> {
+    type Value = M;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<M, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let human_readable = deserializer.is_human_readable();
+
+        let content = match Content::deserialize(deserializer)? {
+            Content::Map(entries) => Content::Map(self.deduplicate_keys(entries)?),
+            Content::Some(content) => match *content {
+                Content::Map(entries) => Content::Some(Box::new(Content::Map(self.deduplicate_keys(entries)?))),
+                content => Content::Some(Box::new(content)),
+            },
+            content => content,
+        };
+
+        M::deserialize(ContentDeserializer::<D::Error>::preserving(content).human_readable(human_readable))
+    }
+}
+
+impl<M> MapKeys<M> {
+    fn deduplicate_keys<'de, E: serde::de::Error>(
+        &self,
+        entries: Vec<(Content<'de>, Content<'de>)>,
+    ) -> Result<Vec<(Content<'de>, Content<'de>)>, E> {
+        match self.policy {
+            DuplicatePolicy::Collect => Ok(collect_entries(entries)),
+            policy => deduplicate(entries, policy),
+        }
+    }
+}
+
+/// # No Duplicate Keys
+///
+/// Wraps a map-like `M`, such as a [HashMap](std::collections::HashMap) or [BTreeMap](std::collections::BTreeMap), so that
+/// deserialization fails when a key is repeated rather than silently keeping its last value.
+///
+/// Fields of derived structs can instead be annotated with `#[duplicates(map_keys = "error")]`, or any other policy.
+///
+/// ## Usage
+///
+///```rust
+///use std::collections::HashMap;
+///
+///use serde_deserialize_duplicates::NoDuplicateKeys;
+///
+///let result = serde_json::from_str::<NoDuplicateKeys<HashMap<String, u32>>>(r#"{ "a": 1, "a": 2 }"#);
+///
+///assert!(result.unwrap_err().to_string().contains("duplicate key `a`"));
+///```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoDuplicateKeys<M>(pub M);
+
+impl<M> NoDuplicateKeys<M> {
+    /// Unwrap the deserialized map
+    pub fn into_inner(self) -> M {
+        self.0
+    }
+}
+
+impl<M> Deref for NoDuplicateKeys<M> {
+    type Target = M;
+
+    fn deref(&self) -> &M {
+        &self.0
+    }
+}
+
+impl<M> DerefMut for NoDuplicateKeys<M> {
+    fn deref_mut(&mut self) -> &mut M {
+        &mut self.0
+    }
+}
+
+impl<M> From<M> for NoDuplicateKeys<M> {
+    fn from(value: M) -> Self {
+        Self(value)
+    }
+}
+
+impl<'de, M: Deserialize<'de>> Deserialize<'de> for NoDuplicateKeys<M> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        MapKeys::new(DuplicatePolicy::Error).deserialize(deserializer).map(Self)
+    }
+}
+
+impl<M: Serialize> Serialize for NoDuplicateKeys<M> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
@@ -0,0 +1,19 @@
+/// # Source Key
+///
+/// Types a field annotated with #[duplicates(source_of = "...")] can be populated with
+pub trait SourceKey {
+    /// Create a value from the key the referenced field's value was taken from, if a single occurrence was used
+    fn from_source_key(key: Option<&str>) -> Self;
+}
+
+impl SourceKey for String {
+    fn from_source_key(key: Option<&str>) -> Self {
+        key.unwrap_or_default().to_owned()
+    }
+}
+
+impl SourceKey for Option<String> {
+    fn from_source_key(key: Option<&str>) -> Self {
+        key.map(str::to_owned)
+    }
+}
@@ -0,0 +1,68 @@
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, DeserializeSeed, Deserializer};
+
+use crate::{context, DuplicateWarning};
+
+/// # With Diagnostics
+///
+/// A [DeserializeSeed] deserializing a `T`, adding a [DuplicateWarning] to the given collector for everything done leniently
+/// by any struct deriving [DeserializeFirstDuplicate](crate::DeserializeFirstDuplicate) or
+/// [DeserializeLastDuplicate](crate::DeserializeLastDuplicate) within it, however deeply nested.
+///
+/// Warnings are only added once deserialization has finished, whether or not it succeeded.
+///
+/// ## Usage
+///
+///```rust
+///use serde::de::DeserializeSeed;
+///use serde::Serialize;
+///use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DuplicateWarning, WithDiagnostics};
+///
+///#[derive(Serialize, DeserializeFirstDuplicate)]
+///struct Reading {
+///    #[duplicates(skip_invalid)]
+///    pub value: u32
+///}
+///
+///let mut warnings = Vec::new();
+///let mut deserializer = serde_json::Deserializer::from_str(r#"{ "value": "n/a", "value": 5 }"#);
+///
+///let reading: Reading = WithDiagnostics::new(&mut warnings).deserialize(&mut deserializer).unwrap();
+///
+///assert_eq!(reading.value, 5);
+///assert!(matches!(warnings[0], DuplicateWarning::SkippedInvalidValue { occurrence: 0, .. }));
+///```
+pub struct WithDiagnostics<W, T> {
+    warnings: W,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<W, T> WithDiagnostics<W, T> {
+    /// Deserialize a `T`, adding any warnings to the given collector
+    pub fn new(warnings: W) -> Self {
+        Self {
+            warnings,
+            target: PhantomData,
+        }
+    }
+}
+
+impl<'de, W, T> DeserializeSeed<'de> for WithDiagnostics<&mut W, T>
+where
+    W: Extend<DuplicateWarning>,
+    T: Deserialize<'de>,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (value, warnings) = context::collect_warnings(|| T::deserialize(deserializer));
+
+        self.warnings.extend(warnings);
+
+        value
+    }
+}
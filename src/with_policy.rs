@@ -0,0 +1,65 @@
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, DeserializeSeed, Deserializer};
+
+use crate::{context, DuplicatePolicy};
+
+/// # With Policy
+///
+/// A [DeserializeSeed] deserializing a `T` with every struct deriving [DeserializeFirstDuplicate](crate::DeserializeFirstDuplicate)
+/// or [DeserializeLastDuplicate](crate::DeserializeLastDuplicate) within it, however deeply nested, following the given
+/// [DuplicatePolicy] instead of its derive's.
+///
+/// ## Usage
+///
+///```rust
+///use serde::de::DeserializeSeed;
+///use serde::Serialize;
+///use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DuplicatePolicy, WithPolicy};
+///
+///#[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+///struct Reading {
+///    pub value: u32
+///}
+///
+///let data = r#"{ "value": 1, "value": 2 }"#;
+///
+///let lenient: Reading = WithPolicy::new(DuplicatePolicy::Last)
+///    .deserialize(&mut serde_json::Deserializer::from_str(data))
+///    .unwrap();
+///
+///assert_eq!(lenient.value, 2);
+///
+///let strict = WithPolicy::<Reading>::new(DuplicatePolicy::Error)
+///    .deserialize(&mut serde_json::Deserializer::from_str(data));
+///
+///assert!(strict.is_err());
+///```
+pub struct WithPolicy<T> {
+    policy: DuplicatePolicy,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<T> WithPolicy<T> {
+    /// Deserialize a `T` following the given policy
+    pub fn new(policy: DuplicatePolicy) -> Self {
+        Self {
+            policy,
+            target: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> DeserializeSeed<'de> for WithPolicy<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        context::with_policy(self.policy, || T::deserialize(deserializer))
+    }
+}
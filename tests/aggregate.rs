@@ -0,0 +1,200 @@
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+#[test]
+fn test_sum() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(sum)]
+        pub count: u64,
+    }
+
+    let data = r#"
+        {
+            "count": 1,
+            "count": 2,
+            "count": 3
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 6);
+}
+
+#[test]
+fn test_min() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(min)]
+        pub count: i32,
+    }
+
+    let data = r#"
+        {
+            "count": 4,
+            "count": -2,
+            "count": 3
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, -2);
+}
+
+#[test]
+fn test_max() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(max)]
+        pub name: String,
+    }
+
+    let data = r#"
+        {
+            "name": "apple",
+            "name": "cherry",
+            "name": "banana"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.name, "cherry".to_owned());
+}
+
+#[test]
+fn test_floats() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(sum)]
+        pub total: f64,
+
+        #[duplicates(min)]
+        pub lowest: f64,
+    }
+
+    let data = r#"
+        {
+            "total": 0.5,
+            "lowest": 2.5,
+            "total": 1.25,
+            "lowest": 1.5
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.total, 1.75);
+    assert_eq!(test_struct.lowest, 1.5);
+}
+
+#[test]
+fn test_single_occurrence() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(sum)]
+        pub count: u64,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "count": 5 }"#).unwrap();
+
+    assert_eq!(test_struct.count, 5);
+}
+
+#[test]
+fn test_aggregate_across_aliases() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "hits")]
+        #[duplicates(sum)]
+        pub count: u64,
+    }
+
+    let data = r#"
+        {
+            "count": 1,
+            "hits": 2
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 3);
+}
+
+#[test]
+fn test_sum_skips_invalid() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(sum, skip_invalid)]
+        pub count: u64,
+    }
+
+    let data = r#"
+        {
+            "count": 1,
+            "count": "n/a",
+            "count": 2
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 3);
+}
+
+#[test]
+fn test_missing_aggregate_with_default() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(default)]
+        #[duplicates(max)]
+        pub count: u64,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(test_struct.count, 0);
+}
+
+#[test]
+fn test_sum_overflow_fails() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(sum)]
+        pub v: u8,
+    }
+
+    let result = serde_json::from_str::<TestStruct>(r#"{ "v": 200, "v": 100 }"#);
+
+    assert!(result.is_err_and(|error| error.to_string().contains("sum of field `v` overflowed")));
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "v": 200, "v": 55 }"#).unwrap();
+
+    assert_eq!(test_struct.v, 255);
+}
+
+#[test]
+fn test_sum_of_type_only_implementing_add() {
+    #[derive(Debug, PartialEq, Serialize, serde::Deserialize)]
+    struct Cents(u64);
+
+    impl std::ops::Add for Cents {
+        type Output = Cents;
+
+        fn add(self, other: Cents) -> Cents {
+            Cents(self.0 + other.0)
+        }
+    }
+
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(sum)]
+        pub total: Cents,
+
+        #[duplicates(sum)]
+        pub wrapping: std::num::Wrapping<u8>,
+    }
+
+    let test_struct: TestStruct =
+        serde_json::from_str(r#"{ "total": 150, "wrapping": 200, "total": 250, "wrapping": 100 }"#).unwrap();
+
+    assert_eq!(test_struct.total, Cents(400));
+    assert_eq!(test_struct.wrapping, std::num::Wrapping(44));
+}
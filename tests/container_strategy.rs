@@ -0,0 +1,195 @@
+use serde::de::DeserializeSeed;
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate, DuplicatePolicy, WithPolicy};
+
+#[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+struct Inner {
+    pub value: u32,
+}
+
+#[test]
+fn test_container_strategy() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "last")]
+    struct TestStruct {
+        pub value: u32,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "value": 1, "value": 2 }"#).unwrap();
+
+    assert_eq!(test_struct.value, 2);
+}
+
+#[test]
+fn test_container_error_strategy() {
+    #[derive(Debug, Serialize, DeserializeLastDuplicate)]
+    #[duplicates(strategy = "error")]
+    struct TestStruct {
+        pub value: u32,
+    }
+
+    let error = serde_json::from_str::<TestStruct>(r#"{ "value": 1, "value": 2 }"#).unwrap_err();
+
+    assert!(error.to_string().contains("duplicate field `value`"));
+}
+
+#[test]
+fn test_container_collect_strategy() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "collect")]
+    struct TestStruct {
+        pub values: Vec<u32>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "values": 1, "values": 2 }"#).unwrap();
+
+    assert_eq!(test_struct.values, vec![1, 2]);
+}
+
+#[test]
+fn test_non_recursive_strategy_not_propagated() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "last")]
+    struct Outer {
+        pub inner: Inner,
+    }
+
+    let outer: Outer = serde_json::from_str(r#"{ "inner": { "value": 1, "value": 2 } }"#).unwrap();
+
+    assert_eq!(outer.inner.value, 1);
+}
+
+#[test]
+fn test_recursive_strategy_propagated() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct Middle {
+        pub inner: Inner,
+    }
+
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "last", recursive)]
+    struct Outer {
+        pub middle: Middle,
+    }
+
+    let data = r#"
+        {
+            "middle": { "inner": { "value": 1 } },
+            "middle": { "inner": { "value": 2, "value": 3 } }
+        }"#;
+
+    let outer: Outer = serde_json::from_str(data).unwrap();
+
+    assert_eq!(outer.middle.inner.value, 3);
+}
+
+#[test]
+fn test_recursive_strategy_with_buffered_fields() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "last", recursive)]
+    struct Outer {
+        #[duplicates(skip_invalid)]
+        pub inner: Inner,
+    }
+
+    let data = r#"
+        {
+            "inner": { "value": 1 },
+            "inner": { "value": "n/a" }
+        }"#;
+
+    let outer: Outer = serde_json::from_str(data).unwrap();
+
+    assert_eq!(outer.inner.value, 1);
+}
+
+#[test]
+fn test_recursive_derive_strategy_propagated() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    #[duplicates(recursive)]
+    struct Outer {
+        pub inner: Inner,
+    }
+
+    let outer: Outer = serde_json::from_str(r#"{ "inner": { "value": 1, "value": 2 } }"#).unwrap();
+
+    assert_eq!(outer.inner.value, 2);
+}
+
+#[test]
+fn test_recursive_strategy_restored() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "last", recursive)]
+    struct Outer {
+        pub inner: Inner,
+    }
+
+    let _: Outer = serde_json::from_str(r#"{ "inner": { "value": 1 } }"#).unwrap();
+
+    let inner: Inner = serde_json::from_str(r#"{ "value": 1, "value": 2 }"#).unwrap();
+
+    assert_eq!(inner.value, 1);
+}
+
+#[test]
+fn test_runtime_policy_takes_precedence() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "last", recursive)]
+    struct Outer {
+        pub inner: Inner,
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#"{ "inner": { "value": 1, "value": 2 } }"#);
+
+    let outer: Outer = WithPolicy::new(DuplicatePolicy::First).deserialize(&mut deserializer).unwrap();
+
+    assert_eq!(outer.inner.value, 1);
+}
+
+#[test]
+fn test_nested_container_strategy_kept_within_recursive_strategy() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "error")]
+    struct Strict {
+        pub name: String,
+    }
+
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "last", recursive)]
+    struct Outer {
+        pub strict: Strict,
+
+        pub inner: Inner,
+    }
+
+    let result = serde_json::from_str::<Outer>(r#"{ "strict": { "name": "a", "name": "b" }, "inner": { "value": 1 } }"#);
+
+    assert!(result.is_err());
+
+    let outer: Outer =
+        serde_json::from_str(r#"{ "strict": { "name": "a" }, "inner": { "value": 1, "value": 2 } }"#).unwrap();
+
+    assert_eq!(outer.strict.name, "a".to_owned());
+    assert_eq!(outer.inner.value, 2);
+}
+
+#[test]
+fn test_runtime_policy_overrides_nested_container_strategy() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "error")]
+    struct Strict {
+        pub name: String,
+    }
+
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "first", recursive)]
+    struct Outer {
+        pub strict: Strict,
+    }
+
+    let outer: Outer = WithPolicy::new(DuplicatePolicy::Last)
+        .deserialize(&mut serde_json::Deserializer::from_str(r#"{ "strict": { "name": "a", "name": "b" } }"#))
+        .unwrap();
+
+    assert_eq!(outer.strict.name, "b".to_owned());
+}
@@ -0,0 +1,105 @@
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+#[test]
+fn test_occurrences_counted() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub value: String,
+
+        #[duplicates(count_of = "value")]
+        pub value_count: usize,
+    }
+
+    let data = r#"
+        {
+            "value": "first",
+            "value": "second",
+            "value": "third"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.value, "first".to_owned());
+    assert_eq!(test_struct.value_count, 3);
+}
+
+#[test]
+fn test_aliases_counted() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(count_of = "breed")]
+        pub breed_count: u8,
+
+        #[serde(alias = "type")]
+        pub breed: String,
+    }
+
+    let data = r#"
+        {
+            "type": "Labrador",
+            "breed": "Retriever"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.breed_count, 2);
+}
+
+#[test]
+fn test_missing_field_counted() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(default)]
+        pub value: Option<String>,
+
+        #[duplicates(count_of = "value")]
+        pub value_count: usize,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(test_struct.value_count, 0);
+}
+
+#[test]
+fn test_count_with_strategy() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub value: i32,
+
+        #[duplicates(count_of = "value")]
+        pub value_count: usize,
+
+        #[duplicates(source_of = "value")]
+        pub value_key: String,
+    }
+
+    let data = r#"
+        {
+            "value": "invalid",
+            "value": 2
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.value, 2);
+    assert_eq!(test_struct.value_count, 2);
+    assert_eq!(test_struct.value_key, "value".to_owned());
+}
+
+#[test]
+fn test_count_overflow() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub value: i32,
+
+        #[duplicates(count_of = "value")]
+        pub value_count: i8,
+    }
+
+    let data = format!("{{ {} }}", vec![r#""value": 1"#; 200].join(", "));
+
+    assert!(serde_json::from_str::<TestStruct>(&data).is_err());
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "serde_json")]
+
+use std::io::{ErrorKind, Write};
+use std::process::{Command, Output, Stdio};
+
+const DATA: &str = r#"{ "a": 1, "b": { "x": 1, "y": 1 }, "a": 2, "b": { "y": 2 } }"#;
+
+fn dedup_json(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_dedup-json"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // The tool exits without reading its input when given invalid arguments, closing the pipe early
+    match child.stdin.take().unwrap().write_all(stdin.as_bytes()) {
+        Err(error) if error.kind() == ErrorKind::BrokenPipe => {}
+        result => result.unwrap(),
+    }
+
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap().trim_end()
+}
+
+#[test]
+fn test_policies() {
+    let cases = [
+        ("first", r#"{"a":1,"b":{"x":1,"y":1}}"#),
+        ("last", r#"{"a":2,"b":{"y":2}}"#),
+        ("merge", r#"{"a":2,"b":{"x":1,"y":2}}"#),
+        ("collect", r#"{"a":[1,2],"b":[{"x":1,"y":1},{"y":2}]}"#),
+    ];
+
+    for (policy, expected) in cases {
+        let output = dedup_json(&["--policy", policy], DATA);
+
+        assert!(output.status.success());
+        assert_eq!(stdout(&output), expected, "policy {policy}");
+    }
+}
+
+#[test]
+fn test_default_policy_is_last() {
+    let output = dedup_json(&["-q"], DATA);
+
+    assert_eq!(stdout(&output), r#"{"a":2,"b":{"y":2}}"#);
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn test_merge_policy_removes_null_keys() {
+    let output = dedup_json(&["-q", "--policy", "merge"], r#"{ "b": { "x": 1, "y": 1 }, "b": { "x": null } }"#);
+
+    assert_eq!(stdout(&output), r#"{"b":{"y":1}}"#);
+}
+
+#[test]
+fn test_error_policy_fails() {
+    let output = dedup_json(&["--policy=error"], DATA);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn test_reports_duplicates() {
+    let output = dedup_json(&[], DATA);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(
+        stderr.lines().collect::<Vec<_>>(),
+        vec![
+            "<stdin>: key `a` repeated 2 times in the root object at line 1 column 3, line 1 column 36",
+            "<stdin>: key `b` repeated 2 times in the root object at line 1 column 11, line 1 column 44",
+        ]
+    );
+}
+
+#[test]
+fn test_check() {
+    let output = dedup_json(&["--check"], DATA);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+
+    let output = dedup_json(&["-c"], r#"{ "a": 1, "b": { "a": 2 } }"#);
+
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_files() {
+    let directory = std::env::temp_dir().join(format!("dedup-json-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    let duplicated = directory.join("duplicated.json");
+    let clean = directory.join("clean.json");
+    std::fs::write(&duplicated, DATA).unwrap();
+    std::fs::write(&clean, "[1, 2]").unwrap();
+
+    let output = dedup_json(&["--policy", "first", clean.to_str().unwrap(), duplicated.to_str().unwrap()], "");
+
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "[1,2]\n{\"a\":1,\"b\":{\"x\":1,\"y\":1}}");
+    assert!(String::from_utf8(output.stderr).unwrap().starts_with(duplicated.to_str().unwrap()));
+
+    let output = dedup_json(&[directory.join("missing.json").to_str().unwrap()], "");
+
+    assert_eq!(output.status.code(), Some(2));
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn test_invalid_input() {
+    let output = dedup_json(&[], r#"{ "a": }"#);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr).unwrap().contains("expected value at line 1 column 8"));
+}
+
+#[test]
+fn test_invalid_arguments() {
+    assert_eq!(dedup_json(&["--policy", "random"], DATA).status.code(), Some(2));
+    assert_eq!(dedup_json(&["--unknown"], DATA).status.code(), Some(2));
+    assert!(dedup_json(&["--help"], DATA).status.success());
+}
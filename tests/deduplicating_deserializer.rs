@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Serialize};
+use serde_deserialize_duplicates::{
+    deserialize_deduplicated, DeduplicatingDeserializer, DeserializeFirstDuplicate, DuplicatePolicy,
+};
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Sensor {
+    pub id: String,
+
+    pub reading: Reading,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Reading {
+    pub value: u32,
+
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+}
+
+const DATA: &str = r#"
+    {
+        "id": "a",
+        "reading": {
+            "value": 1,
+            "labels": { "unit": "C", "unit": "F" },
+            "value": 2
+        },
+        "id": "b"
+    }"#;
+
+fn deserialize<T: for<'de> Deserialize<'de>>(data: &str, policy: DuplicatePolicy) -> serde_json::Result<T> {
+    deserialize_deduplicated(&mut serde_json::Deserializer::from_str(data), policy)
+}
+
+#[test]
+fn test_first_policy() {
+    let sensor: Sensor = deserialize(DATA, DuplicatePolicy::First).unwrap();
+
+    assert_eq!(sensor.id, "a".to_owned());
+    assert_eq!(sensor.reading.value, 1);
+    assert_eq!(sensor.reading.labels["unit"], "C".to_owned());
+}
+
+#[test]
+fn test_last_policy() {
+    let sensor: Sensor = deserialize(DATA, DuplicatePolicy::Last).unwrap();
+
+    assert_eq!(sensor.id, "b".to_owned());
+    assert_eq!(sensor.reading.value, 2);
+    assert_eq!(sensor.reading.labels["unit"], "F".to_owned());
+}
+
+#[test]
+fn test_error_policy() {
+    let error = deserialize::<Sensor>(DATA, DuplicatePolicy::Error).unwrap_err();
+
+    assert!(error.to_string().contains("duplicate key `id`"));
+}
+
+#[test]
+fn test_error_policy_without_duplicates() {
+    let data = r#"{ "id": "a", "reading": { "value": 1 } }"#;
+
+    let sensor: Sensor = deserialize(data, DuplicatePolicy::Error).unwrap();
+
+    assert_eq!(sensor.reading.value, 1);
+}
+
+#[test]
+fn test_collect_policy() {
+    #[derive(Deserialize)]
+    struct Tagged {
+        pub tags: Vec<String>,
+
+        pub name: String,
+    }
+
+    let data = r#"{ "tags": "a", "name": "x", "tags": "b" }"#;
+
+    let tagged: Tagged = deserialize(data, DuplicatePolicy::Collect).unwrap();
+
+    assert_eq!(tagged.tags, vec!["a".to_owned(), "b".to_owned()]);
+    assert_eq!(tagged.name, "x".to_owned());
+
+    let tagged: Tagged = deserialize(r#"{ "tags": "a", "name": "x" }"#, DuplicatePolicy::Collect).unwrap();
+
+    assert_eq!(tagged.tags, vec!["a".to_owned()]);
+
+    let tagged: Tagged = deserialize(r#"{ "tags": ["a"], "name": "x" }"#, DuplicatePolicy::Collect).unwrap();
+
+    assert_eq!(tagged.tags, vec!["a".to_owned()]);
+}
+
+#[test]
+fn test_seed_through_wrapper() {
+    struct Doubled;
+
+    impl<'de> DeserializeSeed<'de> for Doubled {
+        type Value = u32;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<u32, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            #[derive(Deserialize)]
+            struct Value {
+                value: u32,
+            }
+
+            Value::deserialize(deserializer).map(|value| value.value * 2)
+        }
+    }
+
+    let mut json = serde_json::Deserializer::from_str(r#"{ "value": 1, "value": 2 }"#);
+
+    let doubled = Doubled
+        .deserialize(DeduplicatingDeserializer::new(&mut json, DuplicatePolicy::Last))
+        .unwrap();
+
+    assert_eq!(doubled, 4);
+}
+
+#[test]
+fn test_derived_structs_within() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct Derived {
+        pub value: u32,
+    }
+
+    #[derive(Deserialize)]
+    struct Outer {
+        pub derived: Derived,
+    }
+
+    let data = r#"{ "derived": { "value": 1, "value": 2 }, "derived": { "value": 3 } }"#;
+
+    let outer: Outer = deserialize(data, DuplicatePolicy::Last).unwrap();
+
+    assert_eq!(outer.derived.value, 3);
+}
+
+/// Wraps a self-describing deserializer so that it reports a format which is not human readable
+struct Compact<D>(D);
+
+impl<'de, D: serde::Deserializer<'de>> serde::Deserializer<'de> for Compact<D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.0.deserialize_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Records whether the format it was deserialized from is human readable, ignoring the value itself
+#[derive(Debug, PartialEq, Serialize)]
+struct HumanReadable(bool);
+
+impl<'de> Deserialize<'de> for HumanReadable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let human_readable = deserializer.is_human_readable();
+
+        serde::de::IgnoredAny::deserialize(deserializer).map(|_| HumanReadable(human_readable))
+    }
+}
+
+#[test]
+fn test_human_readable_forwarded() {
+    #[derive(Deserialize)]
+    struct Outer {
+        pub inner: Inner,
+        pub values: Vec<HumanReadable>,
+    }
+
+    #[derive(Deserialize)]
+    struct Inner {
+        pub value: HumanReadable,
+    }
+
+    let data = r#"{ "inner": { "value": 1, "value": 2 }, "values": 1, "values": 2 }"#;
+
+    let mut json = serde_json::Deserializer::from_str(data);
+
+    let outer: Outer = deserialize_deduplicated(Compact(&mut json), DuplicatePolicy::Collect).unwrap();
+
+    assert_eq!(outer.inner.value, HumanReadable(false));
+    assert_eq!(outer.values, vec![HumanReadable(false), HumanReadable(false)]);
+
+    let outer: Outer = deserialize(data, DuplicatePolicy::Collect).unwrap();
+
+    assert_eq!(outer.inner.value, HumanReadable(true));
+}
+
+#[test]
+fn test_human_readable_forwarded_to_buffered_fields() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct Derived {
+        #[duplicates(nth = -1)]
+        pub last: HumanReadable,
+
+        #[duplicates(collect)]
+        pub collected: Vec<HumanReadable>,
+    }
+
+    let data = r#"{ "last": 1, "last": 2, "collected": 1 }"#;
+
+    let mut json = serde_json::Deserializer::from_str(data);
+
+    let derived = Derived::deserialize(Compact(&mut json)).unwrap();
+
+    assert_eq!(derived.last, HumanReadable(false));
+    assert_eq!(derived.collected, vec![HumanReadable(false)]);
+}
@@ -0,0 +1,171 @@
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+#[test]
+fn test_malformed_value_uses_default() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(default_on_error)]
+        pub count: i32,
+    }
+
+    let data = r#"
+        {
+            "count": "n/a"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 0);
+}
+
+#[test]
+fn test_valid_value_is_kept() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(default_on_error)]
+        pub count: i32,
+    }
+
+    let data = r#"
+        {
+            "count": 3,
+            "count": "n/a"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 3);
+}
+
+#[test]
+fn test_selected_occurrence_is_not_fallen_through() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(default_on_error)]
+        pub count: i32,
+    }
+
+    let data = r#"
+        {
+            "count": 3,
+            "count": "n/a"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 0);
+}
+
+#[test]
+fn test_combined_with_skip_invalid() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid, default_on_error)]
+        pub count: i32,
+
+        #[duplicates(skip_invalid, default_on_error)]
+        pub name: String,
+    }
+
+    let data = r#"
+        {
+            "count": 3,
+            "count": "n/a",
+            "name": 1,
+            "name": 2
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 3);
+    assert_eq!(test_struct.name, String::new());
+}
+
+#[test]
+fn test_missing_value_still_required() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(default_on_error)]
+        pub count: i32,
+    }
+
+    let error = serde_json::from_str::<TestStruct>("{}").unwrap_err();
+
+    assert!(error.to_string().contains("missing field `count`"));
+}
+
+#[test]
+fn test_warnings_are_reported() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(warnings)]
+        pub warnings: Vec<String>,
+
+        #[duplicates(default_on_error)]
+        pub count: i32,
+
+        #[duplicates(skip_invalid)]
+        pub name: String,
+    }
+
+    let data = r#"
+        {
+            "count": "n/a",
+            "name": 1,
+            "name": "valid"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 0);
+    assert_eq!(test_struct.name, "valid".to_owned());
+    assert_eq!(test_struct.warnings.len(), 2);
+    assert!(test_struct.warnings[0].starts_with("used default value for field `count`"));
+    assert!(test_struct.warnings[1].starts_with("skipped invalid occurrence of field `name`"));
+}
+
+#[test]
+fn test_no_warnings() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub count: i32,
+
+        #[duplicates(warnings)]
+        pub warnings: Vec<String>,
+    }
+
+    let data = r#"
+        {
+            "count": 1,
+            "warnings": ["not read from the data"]
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 1);
+    assert!(test_struct.warnings.is_empty());
+}
+
+#[test]
+fn test_nested_duplicates_kept_for_inner_struct() {
+    #[derive(Serialize, DeserializeLastDuplicate, Default)]
+    struct Inner {
+        pub a: i32,
+    }
+
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(default_on_error)]
+        pub inner: Inner,
+    }
+
+    let data = r#"
+        {
+            "inner": { "a": 1, "a": 2 }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.inner.a, 2);
+}
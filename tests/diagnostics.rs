@@ -0,0 +1,227 @@
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Serialize};
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate, DuplicateWarning, WithDiagnostics};
+
+fn deserialize_with_diagnostics<'de, T: Deserialize<'de>>(data: &'de str) -> (serde_json::Result<T>, Vec<DuplicateWarning>) {
+    let mut warnings = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_str(data);
+
+    let value = WithDiagnostics::new(&mut warnings).deserialize(&mut deserializer);
+
+    (value, warnings)
+}
+
+#[test]
+fn test_discarded_duplicates_warned() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "type")]
+        pub breed: String,
+    }
+
+    let data = r#"
+        {
+            "type": "Labrador",
+            "breed": "Retriever"
+        }"#;
+
+    let (test_struct, warnings) = deserialize_with_diagnostics::<TestStruct>(data);
+
+    assert_eq!(test_struct.unwrap().breed, "Retriever".to_owned());
+    assert_eq!(
+        warnings,
+        vec![DuplicateWarning::DiscardedDuplicate {
+            type_name: "TestStruct",
+            field: "breed",
+            key: "type".to_owned(),
+            occurrence: 0,
+        }]
+    );
+}
+
+#[test]
+fn test_skipped_invalid_values_warned() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub count: u32,
+    }
+
+    let data = r#"
+        {
+            "count": "n/a",
+            "count": 5
+        }"#;
+
+    let (test_struct, warnings) = deserialize_with_diagnostics::<TestStruct>(data);
+
+    assert_eq!(test_struct.unwrap().count, 5);
+    assert_eq!(
+        warnings,
+        vec![DuplicateWarning::SkippedInvalidValue {
+            type_name: "TestStruct",
+            field: "count",
+            key: "count".to_owned(),
+            occurrence: 0,
+            error: "invalid type: string \"n/a\", expected u32".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn test_skipped_invalid_values_not_warned_as_discarded() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub count: u32,
+    }
+
+    let data = r#"
+        {
+            "count": 1,
+            "count": 2,
+            "count": "n/a"
+        }"#;
+
+    let (test_struct, warnings) = deserialize_with_diagnostics::<TestStruct>(data);
+
+    assert_eq!(test_struct.unwrap().count, 2);
+    assert_eq!(
+        warnings,
+        vec![
+            DuplicateWarning::SkippedInvalidValue {
+                type_name: "TestStruct",
+                field: "count",
+                key: "count".to_owned(),
+                occurrence: 2,
+                error: "invalid type: string \"n/a\", expected u32".to_owned(),
+            },
+            DuplicateWarning::DiscardedDuplicate {
+                type_name: "TestStruct",
+                field: "count",
+                key: "count".to_owned(),
+                occurrence: 0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_defaults_used_warned() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(default_on_error)]
+        pub count: u32,
+    }
+
+    let (test_struct, warnings) = deserialize_with_diagnostics::<TestStruct>(r#"{ "count": "n/a" }"#);
+
+    assert_eq!(test_struct.unwrap().count, 0);
+    assert!(matches!(
+        &warnings[..],
+        [DuplicateWarning::DefaultUsed { type_name: "TestStruct", field: "count", errors }] if errors.len() == 1
+    ));
+}
+
+#[test]
+fn test_defaults_used_not_warned_as_discarded() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(default_on_error)]
+        pub count: u32,
+    }
+
+    let (test_struct, warnings) = deserialize_with_diagnostics::<TestStruct>(r#"{ "count": "n/a", "count": 5 }"#);
+
+    assert_eq!(test_struct.unwrap().count, 0);
+    assert_eq!(
+        warnings,
+        vec![DuplicateWarning::DefaultUsed {
+            type_name: "TestStruct",
+            field: "count",
+            errors: vec!["invalid type: string \"n/a\", expected u32".to_owned()],
+        }]
+    );
+}
+
+#[test]
+fn test_nested_structs_warned() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct Inner {
+        pub value: u32,
+    }
+
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct Outer {
+        pub inner: Inner,
+    }
+
+    let data = r#"
+        {
+            "inner": { "value": 1, "value": 2 },
+            "inner": { "value": 3 }
+        }"#;
+
+    let (outer, warnings) = deserialize_with_diagnostics::<Outer>(data);
+
+    assert_eq!(outer.unwrap().inner.value, 3);
+    assert_eq!(
+        warnings,
+        vec![
+            DuplicateWarning::DiscardedDuplicate {
+                type_name: "Inner",
+                field: "value",
+                key: "value".to_owned(),
+                occurrence: 1,
+            },
+            DuplicateWarning::DiscardedDuplicate {
+                type_name: "Outer",
+                field: "inner",
+                key: "inner".to_owned(),
+                occurrence: 0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_no_warnings_without_lenience() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub value: u32,
+    }
+
+    let (test_struct, warnings) = deserialize_with_diagnostics::<TestStruct>(r#"{ "value": 1 }"#);
+
+    assert_eq!(test_struct.unwrap().value, 1);
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_warnings_kept_on_failure() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub count: u32,
+    }
+
+    let (test_struct, warnings) = deserialize_with_diagnostics::<TestStruct>(r#"{ "count": "n/a", "count": true }"#);
+
+    assert!(test_struct.is_err());
+    assert_eq!(warnings.len(), 2);
+}
+
+#[test]
+fn test_warning_display() {
+    let warning = DuplicateWarning::DiscardedDuplicate {
+        type_name: "Dog",
+        field: "breed",
+        key: "type".to_owned(),
+        occurrence: 0,
+    };
+
+    assert_eq!(
+        warning.to_string(),
+        "discarded occurrence 0 of field `Dog.breed` found under `type`"
+    );
+}
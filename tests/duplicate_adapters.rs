@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_deserialize_duplicates::{FirstDuplicate, LastDuplicate};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Owner {
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Size {
+    Small,
+    Large,
+    Custom { height: u32 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Dog {
+    #[serde(alias = "type")]
+    pub breed: String,
+
+    pub owner: Option<Owner>,
+
+    pub size: Size,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+const DATA: &str = r#"
+    {
+        "breed": "Labrador",
+        "owner": { "name": "Alice", "name": "Bob" },
+        "size": "small",
+        "breed": "Retriever",
+        "size": { "custom": { "height": 1, "height": 2 } },
+        "tags": ["friendly"]
+    }"#;
+
+#[test]
+fn test_first_duplicate() {
+    let dog: FirstDuplicate<Dog> = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(
+        dog.into_inner(),
+        Dog {
+            breed: "Labrador".to_owned(),
+            owner: Some(Owner {
+                name: "Alice".to_owned()
+            }),
+            size: Size::Small,
+            tags: vec!["friendly".to_owned()],
+        }
+    );
+}
+
+#[test]
+fn test_last_duplicate() {
+    let dog: LastDuplicate<Dog> = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(
+        dog.into_inner(),
+        Dog {
+            breed: "Retriever".to_owned(),
+            owner: Some(Owner { name: "Bob".to_owned() }),
+            size: Size::Custom { height: 2 },
+            tags: vec!["friendly".to_owned()],
+        }
+    );
+}
+
+#[test]
+fn test_plain_deserialize_fails() {
+    assert!(serde_json::from_str::<Dog>(DATA).is_err());
+}
+
+#[test]
+fn test_maps_deduplicated() {
+    let data = r#"{ "a": 1, "b": 2, "a": 3 }"#;
+
+    let first: FirstDuplicate<BTreeMap<String, u32>> = serde_json::from_str(data).unwrap();
+    let last: LastDuplicate<BTreeMap<String, u32>> = serde_json::from_str(data).unwrap();
+
+    assert_eq!(first.get("a"), Some(&1));
+    assert_eq!(last.get("a"), Some(&3));
+    assert_eq!(last.len(), 2);
+}
+
+#[test]
+fn test_aliases_not_merged() {
+    let data = r#"{ "type": "Labrador", "size": "large" }"#;
+
+    let dog: FirstDuplicate<Dog> = serde_json::from_str(data).unwrap();
+
+    assert_eq!(dog.breed, "Labrador".to_owned());
+    assert_eq!(dog.size, Size::Large);
+    assert_eq!(dog.owner, None);
+}
+
+#[test]
+fn test_borrowed_strings() {
+    #[derive(Deserialize)]
+    struct Borrowing<'a> {
+        pub name: &'a str,
+    }
+
+    let data = r#"{ "name": "first", "name": "second" }"#;
+
+    let borrowing: LastDuplicate<Borrowing> = serde_json::from_str(data).unwrap();
+
+    assert_eq!(borrowing.name, "second");
+}
+
+#[test]
+fn test_invalid_type_fails() {
+    let data = r#"{ "breed": 5, "size": "small" }"#;
+
+    assert!(serde_json::from_str::<FirstDuplicate<Dog>>(data).is_err());
+}
+
+#[test]
+fn test_serialize_transparent() {
+    let owner = FirstDuplicate(Owner {
+        name: "Alice".to_owned(),
+    });
+
+    assert_eq!(serde_json::to_string(&owner).unwrap(), r#"{"name":"Alice"}"#);
+}
@@ -0,0 +1,79 @@
+use serde::Deserialize;
+use serde_deserialize_duplicates::DuplicateMap;
+
+const DATA: &str = r#"
+    {
+        "a": 1,
+        "b": 2,
+        "a": 3,
+        "c": 4,
+        "a": 5
+    }"#;
+
+#[test]
+fn test_every_entry_kept_in_order() {
+    let map: DuplicateMap<String, u32> = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(
+        map.into_vec(),
+        vec![
+            ("a".to_owned(), 1),
+            ("b".to_owned(), 2),
+            ("a".to_owned(), 3),
+            ("c".to_owned(), 4),
+            ("a".to_owned(), 5),
+        ]
+    );
+}
+
+#[test]
+fn test_lookups() {
+    let map: DuplicateMap<String, u32> = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(map.get_first("a"), Some(&1));
+    assert_eq!(map.get_last("a"), Some(&5));
+    assert_eq!(map.get_all("a").copied().collect::<Vec<_>>(), vec![1, 3, 5]);
+    assert_eq!(map.count("a"), 3);
+    assert_eq!(map.get_first("b"), map.get_last("b"));
+    assert_eq!(map.get_first("d"), None);
+    assert!(!map.contains_key("d"));
+}
+
+#[test]
+fn test_nested_in_plain_struct() {
+    #[derive(Deserialize)]
+    struct Document {
+        pub headers: DuplicateMap<String, String>,
+    }
+
+    let data = r#"
+        {
+            "headers": {
+                "Set-Cookie": "a=1",
+                "Set-Cookie": "b=2"
+            }
+        }"#;
+
+    let document: Document = serde_json::from_str(data).unwrap();
+
+    assert_eq!(document.headers.get_all("Set-Cookie").count(), 2);
+}
+
+#[test]
+fn test_serialize_repeats_keys() {
+    let map: DuplicateMap<String, u32> = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"a":1,"b":2,"a":3,"c":4,"a":5}"#);
+}
+
+#[test]
+fn test_collect_and_extend() {
+    let mut map: DuplicateMap<&str, u32> = [("a", 1), ("a", 2)].into_iter().collect();
+
+    map.extend([("b", 3)]);
+    map.push("a", 4);
+
+    assert_eq!(map.len(), 4);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec!["a", "a", "b", "a"]);
+    assert_eq!(map.values().sum::<u32>(), 10);
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "serde_json")]
+
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DuplicatePolicy, DuplicateValue};
+use serde_json::json;
+
+const DATA: &str = r#"
+    {
+        "id": 1,
+        "meta": { "tag": "a", "tag": "b" },
+        "items": [{ "x": 1, "x": 2 }, null, true, -3, 1.5],
+        "id": 2
+    }"#;
+
+#[derive(Serialize, DeserializeFirstDuplicate)]
+struct Meta {
+    pub tag: String,
+}
+
+#[derive(Serialize, DeserializeFirstDuplicate)]
+struct Record {
+    pub id: u32,
+    pub meta: Meta,
+}
+
+#[test]
+fn test_every_entry_kept_in_order() {
+    let value: DuplicateValue = serde_json::from_str(DATA).unwrap();
+    let object = value.as_object().unwrap();
+
+    assert_eq!(object.keys().collect::<Vec<_>>(), vec!["id", "meta", "items", "id"]);
+    assert_eq!(object.get_all("id").map(|id| id.as_number().unwrap().as_u64().unwrap()).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(object.get_first("meta").unwrap().as_object().unwrap().len(), 2);
+    assert!(value.has_duplicates());
+}
+
+#[test]
+fn test_serialize_reproduces_duplicates() {
+    let value: DuplicateValue = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(
+        value.to_string(),
+        r#"{"id":1,"meta":{"tag":"a","tag":"b"},"items":[{"x":1,"x":2},null,true,-3,1.5],"id":2}"#
+    );
+}
+
+#[test]
+fn test_to_value_under_policy() {
+    let value: DuplicateValue = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(
+        value.to_value(DuplicatePolicy::First).unwrap(),
+        json!({ "id": 1, "meta": { "tag": "a" }, "items": [{ "x": 1 }, null, true, -3, 1.5] })
+    );
+    assert_eq!(
+        value.to_value(DuplicatePolicy::Last).unwrap(),
+        json!({ "id": 2, "meta": { "tag": "b" }, "items": [{ "x": 2 }, null, true, -3, 1.5] })
+    );
+    assert_eq!(
+        value.to_value(DuplicatePolicy::Collect).unwrap(),
+        json!({ "id": [1, 2], "meta": { "tag": ["a", "b"] }, "items": [{ "x": [1, 2] }, null, true, -3, 1.5] })
+    );
+    assert!(value.to_value(DuplicatePolicy::Error).is_err());
+    assert!(serde_json::Value::try_from(value).is_err());
+}
+
+#[test]
+fn test_to_merged_value() {
+    let value: DuplicateValue = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(
+        value.to_merged_value().unwrap(),
+        json!({ "id": 2, "meta": { "tag": "b" }, "items": [{ "x": 2 }, null, true, -3, 1.5] })
+    );
+
+    let value: DuplicateValue =
+        serde_json::from_str(r#"{ "a": { "x": 1, "y": { "z": 1 } }, "a": { "x": null, "y": { "w": 2 } } }"#).unwrap();
+
+    assert_eq!(value.to_merged_value().unwrap(), json!({ "a": { "y": { "z": 1, "w": 2 } } }));
+}
+
+#[test]
+fn test_deserialize_into_derived_struct() {
+    let value: DuplicateValue = serde_json::from_str(DATA).unwrap();
+    let record: Record = value.deserialize_into().unwrap();
+
+    assert_eq!(record.id, 1);
+    assert_eq!(record.meta.tag, "a".to_owned());
+
+    let record: Record = value.deserialize_with_policy(DuplicatePolicy::Last).unwrap();
+
+    assert_eq!(record.id, 2);
+    assert_eq!(record.meta.tag, "b".to_owned());
+}
+
+#[test]
+fn test_from_value() {
+    let value = json!({ "a": [1, "b", null], "c": { "d": false } });
+    let duplicate_value = DuplicateValue::from(value.clone());
+
+    assert!(!duplicate_value.has_duplicates());
+    assert_eq!(duplicate_value.to_value(DuplicatePolicy::Error).unwrap(), value);
+}
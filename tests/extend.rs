@@ -0,0 +1,138 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+#[test]
+fn test_extends_vec() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(extend)]
+        pub items: Vec<i32>,
+    }
+
+    let data = r#"
+        {
+            "items": [1, 2],
+            "items": [3]
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.items, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_extends_hash_set() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(extend)]
+        pub items: HashSet<String>,
+    }
+
+    let data = r#"
+        {
+            "items": ["a", "b"],
+            "items": ["b", "c"]
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(
+        test_struct.items,
+        HashSet::from(["a".to_owned(), "b".to_owned(), "c".to_owned()])
+    );
+}
+
+#[test]
+fn test_extends_btree_map() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(extend)]
+        pub items: BTreeMap<String, i32>,
+    }
+
+    let data = r#"
+        {
+            "items": { "a": 1, "b": 1 },
+            "items": { "b": 2 }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(
+        test_struct.items,
+        BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)])
+    );
+}
+
+#[test]
+fn test_extends_across_aliases() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "entries")]
+        #[duplicates(extend)]
+        pub items: Vec<i32>,
+    }
+
+    let data = r#"
+        {
+            "entries": [1],
+            "items": [2]
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.items, vec![1, 2]);
+}
+
+#[test]
+fn test_extend_missing_with_default() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(default)]
+        #[duplicates(extend)]
+        pub items: Vec<i32>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str("{}").unwrap();
+
+    assert!(test_struct.items.is_empty());
+}
+
+#[test]
+fn test_extend_skips_invalid() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(extend, skip_invalid)]
+        pub items: Vec<i32>,
+    }
+
+    let data = r#"
+        {
+            "items": [1],
+            "items": ["invalid"],
+            "items": [2]
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.items, vec![1, 2]);
+}
+
+#[test]
+fn test_extend_invalid_fails() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(extend)]
+        pub items: Vec<i32>,
+    }
+
+    let data = r#"
+        {
+            "items": [1],
+            "items": ["invalid"]
+        }"#;
+
+    assert!(serde_json::from_str::<TestStruct>(data).is_err());
+}
@@ -0,0 +1,98 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::de::DeserializeSeed;
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate, DuplicatePolicy, WithPolicy};
+use serde_json::{json, Value};
+
+const DATA: &str = r#"
+    {
+        "id": 1,
+        "colour": "brown",
+        "size": 3,
+        "colour": "black"
+    }"#;
+
+#[test]
+fn test_first_extra() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub id: u32,
+
+        #[duplicates(extra)]
+        pub extras: BTreeMap<String, Value>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(test_struct.id, 1);
+    assert_eq!(
+        test_struct.extras,
+        BTreeMap::from([("colour".to_owned(), json!("brown")), ("size".to_owned(), json!(3))])
+    );
+}
+
+#[test]
+fn test_last_extra() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        pub id: u32,
+
+        #[duplicates(extra)]
+        pub extras: HashMap<String, Value>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(test_struct.extras["colour"], json!("black"));
+}
+
+#[test]
+fn test_container_strategy_applied_to_extra() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    #[duplicates(strategy = "error")]
+    struct TestStruct {
+        pub id: u32,
+
+        #[duplicates(extra)]
+        pub extras: BTreeMap<String, Value>,
+    }
+
+    let error = serde_json::from_str::<TestStruct>(DATA).unwrap_err();
+
+    assert!(error.to_string().contains("duplicate key `colour`"));
+}
+
+#[test]
+fn test_runtime_collect_policy_applied_to_extra() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub id: u32,
+
+        #[duplicates(extra)]
+        pub extras: BTreeMap<String, Value>,
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_str(DATA);
+
+    let test_struct: TestStruct = WithPolicy::new(DuplicatePolicy::Collect).deserialize(&mut deserializer).unwrap();
+
+    assert_eq!(test_struct.extras["colour"], json!(["brown", "black"]));
+    assert_eq!(test_struct.extras["size"], json!(3));
+}
+
+#[test]
+fn test_extra_passed_through() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub id: u32,
+
+        #[duplicates(extra)]
+        #[serde(flatten)]
+        pub extras: BTreeMap<String, Value>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(serde_json::to_value(&test_struct).unwrap(), json!({ "id": 1, "colour": "brown", "size": 3 }));
+}
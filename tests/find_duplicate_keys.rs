@@ -0,0 +1,110 @@
+use serde_deserialize_duplicates::{find_duplicate_keys, TextPosition};
+
+#[test]
+fn test_no_duplicates() {
+    assert!(find_duplicate_keys(r#"{ "a": 1, "b": { "a": 2 }, "c": [{ "a": 3 }, { "a": 4 }] }"#).unwrap().is_empty());
+    assert!(find_duplicate_keys(r#"[1, -2.5e3, "x", true, false, null, {}, []]"#).unwrap().is_empty());
+}
+
+#[test]
+fn test_positions() {
+    let data = "{\n  \"a\": 1,\n  \"é\": 2, \"a\": 3\n}";
+
+    let duplicates = find_duplicate_keys(data).unwrap();
+
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].pointer, "");
+    assert_eq!(duplicates[0].key, "a");
+    assert_eq!(
+        duplicates[0].occurrences,
+        vec![
+            TextPosition { offset: 4, line: 2, column: 3 },
+            TextPosition { offset: 23, line: 3, column: 11 },
+        ]
+    );
+    assert_eq!(duplicates[0].to_string(), "key `a` repeated 2 times in the root object at line 2 column 3, line 3 column 11");
+}
+
+#[test]
+fn test_nested_pointers() {
+    let data = r#"
+        {
+            "list": [0, { "x": 1, "x": 2, "x": 3 }],
+            "a/b": { "m~n": { "k": 1, "k": 2 } },
+            "list": null
+        }"#;
+
+    let duplicates = find_duplicate_keys(data).unwrap();
+
+    let summary = duplicates
+        .iter()
+        .map(|duplicate| (duplicate.pointer.as_str(), duplicate.key.as_str(), duplicate.occurrences.len()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(summary, vec![("", "list", 2), ("/list/1", "x", 3), ("/a~1b/m~0n", "k", 2)]);
+}
+
+#[test]
+fn test_root_and_empty_key_pointers_displayed_apart() {
+    let duplicates = find_duplicate_keys(r#"{ "": { "a": 1, "a": 2 }, "a": 3, "a": 4 }"#).unwrap();
+
+    let messages = duplicates.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+    assert_eq!(
+        messages,
+        vec![
+            "key `a` repeated 2 times in object `/` at line 1 column 9, line 1 column 17",
+            "key `a` repeated 2 times in the root object at line 1 column 27, line 1 column 35",
+        ]
+    );
+}
+
+#[test]
+fn test_escaped_keys_compared_decoded() {
+    let duplicates = find_duplicate_keys(r#"{ "café": 1, "caf\u00e9": 2, "\ud83d\ude00": 3, "😀": 4, "\"": 5, "\u0022": 6 }"#).unwrap();
+
+    assert_eq!(duplicates.len(), 3);
+    assert_eq!(duplicates[0].key, "café");
+    assert_eq!(duplicates[1].key, "😀");
+    assert_eq!(duplicates[2].key, "\"");
+}
+
+#[test]
+fn test_deeply_nested_document() {
+    let data = format!("{}{{\"a\": 1, \"a\": 2}}{}", "[".repeat(100_000), "]".repeat(100_000));
+
+    let duplicates = find_duplicate_keys(&data).unwrap();
+
+    assert_eq!(duplicates.len(), 1);
+    assert!(duplicates[0].pointer.starts_with("/0/0/0"));
+}
+
+#[test]
+fn test_invalid_documents() {
+    for data in ["", "{", r#"{ "a" 1 }"#, r#"{ "a": 1, }"#, "[1 2]", "01", "1.", r#""\x""#, "nul", "{} {}", "\"\n\""] {
+        assert!(find_duplicate_keys(data).is_err(), "{data:?} should be rejected");
+    }
+
+    let error = find_duplicate_keys("{\n  \"a\": tru }").unwrap_err();
+
+    assert_eq!(error.position, TextPosition { offset: 9, line: 2, column: 8 });
+    assert_eq!(error.to_string(), "expected value at line 2 column 8");
+}
+
+#[test]
+fn test_long_single_line_document() {
+    let entries = (0..200_000).map(|index| format!(r#""é{}": {index}"#, index % 100_000)).collect::<Vec<_>>();
+
+    let data = format!("{{{}}}", entries.join(","));
+
+    let duplicates = find_duplicate_keys(&data).unwrap();
+
+    assert_eq!(duplicates.len(), 100_000);
+    assert_eq!(duplicates[0].key, "é0");
+    assert_eq!(duplicates[0].occurrences[0], TextPosition { offset: 1, line: 1, column: 2 });
+
+    let last = &duplicates[99_999].occurrences[1];
+
+    assert_eq!(last.line, 1);
+    assert_eq!(last.column, data[..last.offset].chars().count() + 1);
+}
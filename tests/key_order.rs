@@ -0,0 +1,81 @@
+use serde_deserialize_duplicates::{
+    DeserializeFirstDuplicate, DeserializeLastDuplicate, KeyOrder, KeyOrderEntry, SerializeDuplicates,
+};
+
+#[test]
+fn test_every_key_recorded_in_order() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "type")]
+        pub breed: String,
+
+        #[duplicates(key_order)]
+        pub order: KeyOrder,
+    }
+
+    let data = r#"
+        {
+            "breed": "Retriever",
+            "unknown": true,
+            "type": "Labrador"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(
+        test_struct.order.entries,
+        vec![
+            KeyOrderEntry {
+                key: "breed".to_owned(),
+                field: Some("breed"),
+                selected: true,
+            },
+            KeyOrderEntry {
+                key: "unknown".to_owned(),
+                field: None,
+                selected: false,
+            },
+            KeyOrderEntry {
+                key: "type".to_owned(),
+                field: Some("breed"),
+                selected: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_selected_occurrence_marked() {
+    #[derive(SerializeDuplicates, DeserializeLastDuplicate)]
+    struct TestStruct {
+        pub value: u32,
+
+        #[duplicates(key_order)]
+        pub order: KeyOrder,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{"value":1,"value":2}"#).unwrap();
+
+    assert_eq!(
+        test_struct.order.iter().map(|entry| entry.selected).collect::<Vec<_>>(),
+        [false, true]
+    );
+    assert_eq!(test_struct.order.value_position("value"), Some(1));
+}
+
+#[test]
+fn test_empty_document() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(default)]
+        pub value: Option<u32>,
+
+        #[duplicates(key_order)]
+        pub order: KeyOrder,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str("{}").unwrap();
+
+    assert!(test_struct.order.is_empty());
+    assert_eq!(test_struct.order.value_position("value"), None);
+}
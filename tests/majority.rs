@@ -0,0 +1,143 @@
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+#[test]
+fn test_most_frequent_value() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(majority)]
+        pub reading: i32,
+    }
+
+    let data = r#"
+        {
+            "reading": 1,
+            "reading": 2,
+            "reading": 2
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.reading, 2);
+}
+
+#[test]
+fn test_tie_defaults_to_first() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(majority)]
+        pub reading: String,
+    }
+
+    let data = r#"
+        {
+            "reading": "a",
+            "reading": "b",
+            "reading": "b",
+            "reading": "a"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.reading, "a".to_owned());
+}
+
+#[test]
+fn test_tie_first() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(majority = "first")]
+        pub reading: i32,
+    }
+
+    let data = r#"
+        {
+            "reading": 3,
+            "reading": 4
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.reading, 3);
+}
+
+#[test]
+fn test_tie_last() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(majority = "last")]
+        pub reading: i32,
+    }
+
+    let data = r#"
+        {
+            "reading": 3,
+            "reading": 4,
+            "reading": 4,
+            "reading": 3
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.reading, 3);
+}
+
+#[test]
+fn test_tie_error() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(majority = "error")]
+        pub reading: i32,
+    }
+
+    let data = r#"
+        {
+            "reading": 3,
+            "reading": 4
+        }"#;
+
+    let error = serde_json::from_str::<TestStruct>(data).unwrap_err();
+
+    assert!(error.to_string().contains("no single most frequent occurrence of field `reading`"));
+}
+
+#[test]
+fn test_no_tie_with_error_tie_breaker() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(majority = "error", skip_invalid)]
+        pub reading: i32,
+    }
+
+    let data = r#"
+        {
+            "reading": 3,
+            "reading": "invalid",
+            "reading": 4,
+            "reading": 4
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.reading, 4);
+}
+
+#[test]
+fn test_partial_eq_values() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(majority)]
+        pub reading: Vec<f64>,
+    }
+
+    let data = r#"
+        {
+            "reading": [0.5, 1.5],
+            "reading": [0.5, 1.5],
+            "reading": [0.5]
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.reading, vec![0.5, 1.5]);
+}
@@ -0,0 +1,202 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::de::DeserializeSeed;
+use serde::Serialize;
+use serde_deserialize_duplicates::{
+    DeserializeFirstDuplicate, DeserializeLastDuplicate, DuplicatePolicy, NoDuplicateKeys, WithPolicy,
+};
+
+const DATA: &str = r#"
+    {
+        "labels": {
+            "unit": "C",
+            "zone": "north",
+            "unit": "F"
+        }
+    }"#;
+
+#[test]
+fn test_no_duplicate_keys() {
+    let result = serde_json::from_str::<NoDuplicateKeys<HashMap<String, String>>>(r#"{ "a": "1", "a": "2" }"#);
+
+    assert!(result.unwrap_err().to_string().contains("duplicate key `a`"));
+}
+
+#[test]
+fn test_no_duplicate_keys_without_duplicates() {
+    let map: NoDuplicateKeys<BTreeMap<String, u32>> = serde_json::from_str(r#"{ "a": 1, "b": 2 }"#).unwrap();
+
+    assert_eq!(map.into_inner(), BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]));
+}
+
+#[test]
+fn test_map_keys_error() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(map_keys = "error")]
+        pub labels: HashMap<String, String>,
+    }
+
+    let error = serde_json::from_str::<TestStruct>(DATA).unwrap_err();
+
+    assert!(error.to_string().contains("duplicate key `unit`"));
+}
+
+#[test]
+fn test_map_keys_first() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(map_keys = "first")]
+        pub labels: HashMap<String, String>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(test_struct.labels["unit"], "C".to_owned());
+    assert_eq!(test_struct.labels["zone"], "north".to_owned());
+}
+
+#[test]
+fn test_map_keys_last() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(map_keys = "last")]
+        pub labels: BTreeMap<String, String>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(test_struct.labels["unit"], "F".to_owned());
+}
+
+#[test]
+fn test_map_keys_collect() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(map_keys = "collect")]
+        pub readings: BTreeMap<String, Vec<u32>>,
+    }
+
+    let data = r#"
+        {
+            "readings": {
+                "a": 1,
+                "b": 3,
+                "a": 2
+            }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.readings["a"], vec![1, 2]);
+    assert_eq!(test_struct.readings["b"], vec![3]);
+}
+
+#[test]
+fn test_map_keys_optional() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(map_keys = "last")]
+        #[serde(default)]
+        pub labels: Option<HashMap<String, String>>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(DATA).unwrap();
+    let missing: TestStruct = serde_json::from_str(r#"{ "labels": null }"#).unwrap();
+
+    assert_eq!(test_struct.labels.unwrap()["unit"], "F".to_owned());
+    assert!(missing.labels.is_none());
+}
+
+#[test]
+fn test_map_keys_with_duplicated_field() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(map_keys = "first")]
+        pub labels: HashMap<String, String>,
+    }
+
+    let data = r#"
+        {
+            "labels": { "unit": "C" },
+            "labels": { "unit": "K", "unit": "F" }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.labels["unit"], "K".to_owned());
+}
+
+#[test]
+fn test_map_keys_with_extend() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(extend, map_keys = "error")]
+        pub labels: HashMap<String, String>,
+    }
+
+    let data = r#"
+        {
+            "labels": { "unit": "C" },
+            "labels": { "zone": "north", "zone": "south" }
+        }"#;
+
+    assert!(serde_json::from_str::<TestStruct>(data).is_err());
+}
+
+#[test]
+fn test_map_keys_under_collect_policy() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(map_keys = "error")]
+        pub labels: HashMap<String, String>,
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_str(DATA);
+
+    let result: Result<TestStruct, _> = WithPolicy::new(DuplicatePolicy::Collect).deserialize(&mut deserializer);
+
+    assert!(result.unwrap_err().to_string().contains("duplicate key `unit`"));
+}
+
+#[test]
+fn test_map_keys_with_skip_invalid() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(map_keys = "error", skip_invalid)]
+        pub labels: HashMap<String, String>,
+    }
+
+    let data = r#"
+        {
+            "labels": { "unit": "C", "unit": "F" },
+            "labels": { "unit": "K" }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.labels["unit"], "K".to_owned());
+}
+
+#[test]
+fn test_map_keys_collect_array_values() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(map_keys = "collect")]
+        pub readings: HashMap<String, Vec<Vec<u32>>>,
+    }
+
+    let data = r#"
+        {
+            "readings": {
+                "a": [1, 2],
+                "b": [3],
+                "a": [4]
+            }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.readings["a"], vec![vec![1, 2], vec![4]]);
+    assert_eq!(test_struct.readings["b"], vec![vec![3]]);
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Meta {
+    pub a: Option<i32>,
+    pub b: Option<i32>,
+    pub nested: Option<Nested>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Nested {
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+}
+
+#[test]
+fn test_merges_objects() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(merge)]
+        pub meta: Meta,
+    }
+
+    let data = r#"
+        {
+            "meta": { "a": 1 },
+            "meta": { "b": 2 }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(
+        test_struct.meta,
+        Meta {
+            a: Some(1),
+            b: Some(2),
+            nested: None
+        }
+    );
+}
+
+#[test]
+fn test_later_keys_override_recursively() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(merge)]
+        pub meta: Meta,
+    }
+
+    let data = r#"
+        {
+            "meta": { "a": 1, "nested": { "x": 1, "y": 1 } },
+            "meta": { "a": 2, "nested": { "y": 2 } }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(
+        test_struct.meta,
+        Meta {
+            a: Some(2),
+            b: None,
+            nested: Some(Nested {
+                x: Some(1),
+                y: Some(2)
+            })
+        }
+    );
+}
+
+#[test]
+fn test_null_removes_key() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(merge)]
+        pub meta: serde_json::Value,
+    }
+
+    let data = r#"
+        {
+            "meta": { "a": 1, "b": 1 },
+            "meta": { "a": null }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.meta, serde_json::json!({ "b": 1 }));
+}
+
+#[test]
+fn test_non_object_replaces() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(merge)]
+        pub meta: serde_json::Value,
+    }
+
+    let data = r#"
+        {
+            "meta": { "a": 1 },
+            "meta": [1, 2]
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.meta, serde_json::json!([1, 2]));
+}
+
+#[test]
+fn test_merges_across_aliases() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "metadata")]
+        #[duplicates(merge)]
+        pub meta: Meta,
+    }
+
+    let data = r#"
+        {
+            "metadata": { "a": 1 },
+            "meta": { "b": 2 }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.meta.a, Some(1));
+    assert_eq!(test_struct.meta.b, Some(2));
+}
+
+#[test]
+fn test_merged_value_with_default_on_error() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(merge, default_on_error)]
+        pub meta: Option<Meta>,
+    }
+
+    let data = r#"
+        {
+            "meta": { "a": 1 },
+            "meta": { "a": "invalid" }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.meta, None);
+}
+
+#[test]
+fn test_nested_duplicates_kept_for_inner_struct() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct Inner {
+        pub a: i32,
+        pub b: Option<i32>,
+    }
+
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(merge)]
+        pub inner: Inner,
+    }
+
+    let data = r#"
+        {
+            "inner": { "a": 1, "a": 2 },
+            "inner": { "b": 3 }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.inner.a, 1);
+    assert_eq!(test_struct.inner.b, Some(3));
+}
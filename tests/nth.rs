@@ -0,0 +1,200 @@
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+#[test]
+fn test_second_occurrence() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(nth = 2)]
+        pub value: String,
+    }
+
+    let data = r#"
+        {
+            "value": "first",
+            "value": "second",
+            "value": "third"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.value, "second".to_owned());
+}
+
+#[test]
+fn test_negative_position() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(nth = -2)]
+        pub value: String,
+    }
+
+    let data = r#"
+        {
+            "value": "first",
+            "value": "second",
+            "value": "third"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.value, "second".to_owned());
+}
+
+#[test]
+fn test_last_position() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(nth = -1)]
+        pub value: String,
+    }
+
+    let data = r#"
+        {
+            "value": "first",
+            "value": "second",
+            "value": "third"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.value, "third".to_owned());
+}
+
+#[test]
+fn test_position_across_aliases() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "other")]
+        #[duplicates(nth = 1)]
+        pub value: String,
+    }
+
+    let data = r#"
+        {
+            "other": "first",
+            "value": "second"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.value, "first".to_owned());
+}
+
+#[test]
+fn test_too_few_occurrences() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(nth = 3)]
+        pub value: String,
+    }
+
+    let data = r#"
+        {
+            "value": "first",
+            "value": "second"
+        }"#;
+
+    let error = serde_json::from_str::<TestStruct>(data).unwrap_err();
+
+    assert!(error.to_string().contains("missing field `value`"));
+}
+
+#[test]
+fn test_too_few_occurrences_with_default() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(default)]
+        #[duplicates(nth = 2)]
+        pub value: String,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "value": "first" }"#).unwrap();
+
+    assert_eq!(test_struct.value, String::new());
+}
+
+#[test]
+fn test_other_occurrences_are_not_validated() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(nth = 2, default_on_error)]
+        pub value: i32,
+    }
+
+    let data = r#"
+        {
+            "value": "invalid",
+            "value": 2
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.value, 2);
+}
+
+#[test]
+fn test_other_occurrences_may_have_another_type() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(nth = 2)]
+        pub body: i32,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "body": "x", "body": 2, "body": [true] }"#).unwrap();
+
+    assert_eq!(test_struct.body, 2);
+}
+
+#[test]
+fn test_other_occurrences_may_have_another_type_counting_from_the_end() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(nth = -2)]
+        pub body: i32,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "body": "x", "body": 2, "body": { "y": null } }"#).unwrap();
+
+    assert_eq!(test_struct.body, 2);
+}
+
+#[test]
+fn test_position_among_valid_occurrences_with_skip_invalid() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(nth = 2, skip_invalid)]
+        pub v: u32,
+
+        #[duplicates(nth = -1, skip_invalid)]
+        pub w: u32,
+    }
+
+    let data = r#"
+        {
+            "v": 1,
+            "v": "x",
+            "v": 3,
+            "w": 1,
+            "w": 2,
+            "w": "x"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.v, 3);
+    assert_eq!(test_struct.w, 2);
+}
+
+#[test]
+fn test_too_few_valid_occurrences_with_skip_invalid() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(nth = 2, skip_invalid)]
+        pub v: u32,
+    }
+
+    let result = serde_json::from_str::<TestStruct>(r#"{ "v": 1, "v": "x" }"#);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,230 @@
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Serialize};
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate, DuplicatePolicy, WithPolicy};
+
+fn deserialize_with_policy<'de, T: Deserialize<'de>>(policy: DuplicatePolicy, data: &'de str) -> serde_json::Result<T> {
+    WithPolicy::new(policy).deserialize(&mut serde_json::Deserializer::from_str(data))
+}
+
+#[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+struct FirstStruct {
+    #[serde(alias = "type")]
+    pub breed: String,
+}
+
+#[derive(Debug, Serialize, DeserializeLastDuplicate)]
+struct LastStruct {
+    #[serde(alias = "type")]
+    pub breed: String,
+}
+
+const DATA: &str = r#"
+    {
+        "type": "Labrador",
+        "breed": "Retriever"
+    }"#;
+
+#[test]
+fn test_derive_strategy_without_policy() {
+    let first_struct: FirstStruct = serde_json::from_str(DATA).unwrap();
+    let last_struct: LastStruct = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(first_struct.breed, "Labrador".to_owned());
+    assert_eq!(last_struct.breed, "Retriever".to_owned());
+}
+
+#[test]
+fn test_first_policy() {
+    let last_struct: LastStruct = deserialize_with_policy(DuplicatePolicy::First, DATA).unwrap();
+
+    assert_eq!(last_struct.breed, "Labrador".to_owned());
+}
+
+#[test]
+fn test_last_policy() {
+    let first_struct: FirstStruct = deserialize_with_policy(DuplicatePolicy::Last, DATA).unwrap();
+
+    assert_eq!(first_struct.breed, "Retriever".to_owned());
+}
+
+#[test]
+fn test_error_policy() {
+    let error = deserialize_with_policy::<FirstStruct>(DuplicatePolicy::Error, DATA).unwrap_err();
+
+    assert!(error.to_string().contains("duplicate field `breed`"));
+}
+
+#[test]
+fn test_error_policy_without_duplicates() {
+    let first_struct: FirstStruct = deserialize_with_policy(DuplicatePolicy::Error, r#"{ "breed": "Labrador" }"#).unwrap();
+
+    assert_eq!(first_struct.breed, "Labrador".to_owned());
+}
+
+#[test]
+fn test_collect_policy() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub tags: Vec<String>,
+
+        pub count: u32,
+    }
+
+    let data = r#"
+        {
+            "tags": "rust",
+            "count": 1,
+            "tags": "serde"
+        }"#;
+
+    let test_struct: TestStruct = deserialize_with_policy(DuplicatePolicy::Collect, data).unwrap();
+
+    assert_eq!(test_struct.tags, vec!["rust".to_owned(), "serde".to_owned()]);
+    assert_eq!(test_struct.count, 1);
+}
+
+#[test]
+fn test_collect_policy_single_occurrence() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub tags: Vec<String>,
+
+        pub nested: Vec<Vec<String>>,
+
+        pub name: String,
+    }
+
+    let data = r#"{ "tags": "rust", "nested": ["a"], "name": "x" }"#;
+
+    let test_struct: TestStruct = deserialize_with_policy(DuplicatePolicy::Collect, data).unwrap();
+
+    assert_eq!(test_struct.tags, vec!["rust".to_owned()]);
+    assert_eq!(test_struct.nested, vec![vec!["a".to_owned()]]);
+    assert_eq!(test_struct.name, "x".to_owned());
+
+    let test_struct: TestStruct =
+        deserialize_with_policy(DuplicatePolicy::Collect, r#"{ "tags": ["rust"], "nested": [["a"]], "name": "x" }"#)
+            .unwrap();
+
+    assert_eq!(test_struct.tags, vec!["rust".to_owned()]);
+    assert_eq!(test_struct.nested, vec![vec!["a".to_owned()]]);
+}
+
+#[test]
+fn test_collect_policy_one_element_per_occurrence() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub tags: Vec<Vec<String>>,
+    }
+
+    let data = r#"{ "tags": ["a"], "tags": ["b", "c"] }"#;
+
+    let test_struct: TestStruct = deserialize_with_policy(DuplicatePolicy::Collect, data).unwrap();
+
+    assert_eq!(test_struct.tags, vec![vec!["a".to_owned()], vec!["b".to_owned(), "c".to_owned()]]);
+}
+
+#[test]
+fn test_collect_policy_scalar_fails() {
+    let result = deserialize_with_policy::<FirstStruct>(DuplicatePolicy::Collect, DATA);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_collect_policy_with_skip_invalid() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub values: Vec<u32>,
+    }
+
+    let data = r#"
+        {
+            "values": 1,
+            "values": 2
+        }"#;
+
+    let test_struct: TestStruct = deserialize_with_policy(DuplicatePolicy::Collect, data).unwrap();
+
+    assert_eq!(test_struct.values, vec![1, 2]);
+
+    let test_struct: TestStruct = deserialize_with_policy(DuplicatePolicy::Collect, r#"{ "values": 1 }"#).unwrap();
+
+    assert_eq!(test_struct.values, vec![1]);
+}
+
+#[test]
+fn test_last_policy_with_skip_invalid() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub count: u32,
+    }
+
+    let data = r#"
+        {
+            "count": 1,
+            "count": 2,
+            "count": "n/a"
+        }"#;
+
+    let test_struct: TestStruct = deserialize_with_policy(DuplicatePolicy::Last, data).unwrap();
+
+    assert_eq!(test_struct.count, 2);
+}
+
+#[test]
+fn test_field_strategy_kept() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(sum)]
+        pub total: u32,
+
+        pub value: u32,
+    }
+
+    let data = r#"
+        {
+            "total": 1,
+            "value": 1,
+            "total": 2,
+            "value": 2
+        }"#;
+
+    let test_struct: TestStruct = deserialize_with_policy(DuplicatePolicy::Last, data).unwrap();
+
+    assert_eq!(test_struct.total, 3);
+    assert_eq!(test_struct.value, 2);
+}
+
+#[test]
+fn test_policy_nested() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct Inner {
+        pub value: u32,
+    }
+
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct Outer {
+        pub inner: Inner,
+    }
+
+    let data = r#"
+        {
+            "inner": { "value": 1, "value": 2 }
+        }"#;
+
+    let outer: Outer = deserialize_with_policy(DuplicatePolicy::Last, data).unwrap();
+
+    assert_eq!(outer.inner.value, 2);
+}
+
+#[test]
+fn test_policy_restored() {
+    let _ = deserialize_with_policy::<FirstStruct>(DuplicatePolicy::Error, DATA);
+
+    let first_struct: FirstStruct = serde_json::from_str(DATA).unwrap();
+
+    assert_eq!(first_struct.breed, "Labrador".to_owned());
+}
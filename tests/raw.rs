@@ -0,0 +1,80 @@
+#![cfg(feature = "serde_json")]
+
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate, SerializeDuplicates};
+use serde_json::value::RawValue;
+
+#[derive(SerializeDuplicates, DeserializeLastDuplicate)]
+struct TestStruct {
+    pub id: u32,
+
+    #[duplicates(raw)]
+    #[serde(alias = "sum")]
+    pub amount: Vec<Box<RawValue>>,
+}
+
+fn raw_texts(values: &[Box<RawValue>]) -> Vec<&str> {
+    values.iter().map(|value| value.get()).collect()
+}
+
+#[test]
+fn test_every_occurrence_kept_unparsed() {
+    let data = r#"
+        {
+            "amount": 1.50,
+            "id": 1,
+            "sum": { "value" : 1.5 },
+            "amount": "1.5",
+            "id": 2
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.id, 2);
+    assert_eq!(raw_texts(&test_struct.amount), vec!["1.50", r#"{ "value" : 1.5 }"#, r#""1.5""#]);
+}
+
+#[test]
+fn test_single_occurrence() {
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "id": 1, "amount": [1, 2] }"#).unwrap();
+
+    assert_eq!(raw_texts(&test_struct.amount), vec!["[1, 2]"]);
+}
+
+#[test]
+fn test_missing_field() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct DefaultStruct {
+        #[duplicates(raw)]
+        #[serde(default)]
+        pub amount: Vec<Box<RawValue>>,
+    }
+
+    assert!(serde_json::from_str::<TestStruct>(r#"{ "id": 1 }"#).is_err());
+    assert!(serde_json::from_str::<DefaultStruct>(r#"{}"#).unwrap().amount.is_empty());
+}
+
+#[test]
+fn test_round_trip() {
+    let data = r#"{"id":1,"amount":1.50,"amount":"1.5","sum":null}"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), r#"{"id":1,"amount":1.50,"amount":"1.5","amount":null}"#);
+}
+
+#[test]
+fn test_count_of_raw_field() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct CountedStruct {
+        #[duplicates(raw)]
+        pub value: Vec<Box<RawValue>>,
+
+        #[duplicates(count_of = "value")]
+        pub value_count: usize,
+    }
+
+    let counted_struct: CountedStruct = serde_json::from_str(r#"{ "value": 1, "value": 2 }"#).unwrap();
+
+    assert_eq!(counted_struct.value.len(), 2);
+    assert_eq!(counted_struct.value_count, 2);
+}
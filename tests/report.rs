@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use serde_deserialize_duplicates::{
+    deserialize_with_report, DeserializeFirstDuplicate, DeserializeLastDuplicate, DuplicatedField,
+};
+
+fn report_for<'de, T: Deserialize<'de>>(data: &'de str) -> (T, serde_deserialize_duplicates::DuplicateReport) {
+    let mut deserializer = serde_json::Deserializer::from_str(data);
+
+    deserialize_with_report(&mut deserializer).unwrap()
+}
+
+#[test]
+fn test_no_duplicates() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub value: String,
+    }
+
+    let (_, report) = report_for::<TestStruct>(r#"{ "value": "only" }"#);
+
+    assert!(report.is_empty());
+}
+
+#[test]
+fn test_first_duplicate_reported() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub value: String,
+    }
+
+    let data = r#"
+        {
+            "value": "first",
+            "value": "second",
+            "value": "third"
+        }"#;
+
+    let (test_struct, report) = report_for::<TestStruct>(data);
+
+    assert_eq!(test_struct.value, "first".to_owned());
+    assert_eq!(
+        report.duplicates,
+        vec![DuplicatedField {
+            type_name: "TestStruct",
+            field: "value",
+            keys: vec!["value".to_owned(), "value".to_owned(), "value".to_owned()],
+            selected: Some(0),
+        }]
+    );
+    assert_eq!(report.duplicates[0].occurrences(), 3);
+}
+
+#[test]
+fn test_aliases_reported() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "type")]
+        pub breed: String,
+
+        pub name: String,
+    }
+
+    let data = r#"
+        {
+            "breed": "Labrador",
+            "name": "Rex",
+            "type": "Retriever"
+        }"#;
+
+    let (_, report) = report_for::<TestStruct>(data);
+
+    assert_eq!(report.duplicates.len(), 1);
+    assert_eq!(report.duplicates[0].field, "breed");
+    assert_eq!(report.duplicates[0].keys, vec!["breed".to_owned(), "type".to_owned()]);
+    assert_eq!(report.duplicates[0].selected, Some(1));
+}
+
+#[test]
+fn test_selected_occurrence_per_strategy() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub skipped: i32,
+
+        #[duplicates(nth = -2)]
+        pub nth: i32,
+
+        #[duplicates(sum)]
+        pub sum: i32,
+    }
+
+    let data = r#"
+        {
+            "skipped": "invalid",
+            "skipped": 1,
+            "nth": 1,
+            "nth": 2,
+            "nth": 3,
+            "sum": 1,
+            "sum": 2
+        }"#;
+
+    let (test_struct, report) = report_for::<TestStruct>(data);
+
+    assert_eq!(test_struct.skipped, 1);
+    assert_eq!(test_struct.nth, 2);
+    assert_eq!(test_struct.sum, 3);
+
+    let selections = report
+        .iter()
+        .map(|duplicate| (duplicate.field, duplicate.selected))
+        .collect::<Vec<_>>();
+
+    assert_eq!(selections, vec![("skipped", Some(1)), ("nth", Some(1)), ("sum", None)]);
+}
+
+#[test]
+fn test_nested_structs_reported() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct Inner {
+        pub value: i32,
+    }
+
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct Outer {
+        pub inner: Inner,
+    }
+
+    let data = r#"
+        {
+            "inner": { "value": 1, "value": 2 },
+            "inner": { "value": 3 }
+        }"#;
+
+    let (outer, report) = report_for::<Outer>(data);
+
+    assert_eq!(outer.inner.value, 3);
+
+    let fields = report
+        .iter()
+        .map(|duplicate| (duplicate.type_name, duplicate.field))
+        .collect::<Vec<_>>();
+
+    assert_eq!(fields, vec![("Inner", "value"), ("Outer", "inner")]);
+}
+
+#[test]
+fn test_nothing_recorded_outside_report() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub value: i32,
+    }
+
+    let _: TestStruct = serde_json::from_str(r#"{ "value": 1, "value": 2 }"#).unwrap();
+
+    let (_, report) = report_for::<TestStruct>(r#"{ "value": 1 }"#);
+
+    assert!(report.is_empty());
+}
@@ -0,0 +1,123 @@
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+mod resolvers {
+    pub fn longest(occurrences: Vec<(&str, String)>) -> Result<String, String> {
+        occurrences
+            .into_iter()
+            .map(|(_, value)| value)
+            .max_by_key(String::len)
+            .ok_or_else(|| "no value found".to_owned())
+    }
+
+    pub fn reject_conflicts(occurrences: Vec<(&str, i32)>) -> Result<i32, String> {
+        let (_, first) = occurrences[0];
+
+        if occurrences.iter().all(|(_, value)| *value == first) {
+            Ok(first)
+        } else {
+            Err("conflicting values".to_owned())
+        }
+    }
+}
+
+#[test]
+fn test_resolver_chooses_value() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(resolve = "resolvers::longest")]
+        pub value: String,
+    }
+
+    let data = r#"
+        {
+            "value": "a",
+            "value": "abc",
+            "value": "ab"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.value, "abc".to_owned());
+}
+
+#[test]
+fn test_resolver_receives_keys() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "type")]
+        #[duplicates(resolve = "join")]
+        pub breed: String,
+    }
+
+    fn join(occurrences: Vec<(&str, String)>) -> Result<String, String> {
+        Ok(occurrences
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<String>>()
+            .join(","))
+    }
+
+    let data = r#"
+        {
+            "type": "Labrador",
+            "breed": "Retriever"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.breed, "type=Labrador,breed=Retriever".to_owned());
+}
+
+#[test]
+fn test_resolver_error_fails_deserialization() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(resolve = "resolvers::reject_conflicts")]
+        pub value: i32,
+    }
+
+    let data = r#"
+        {
+            "value": 1,
+            "value": 2
+        }"#;
+
+    let error = serde_json::from_str::<TestStruct>(data).unwrap_err();
+
+    assert!(error.to_string().contains("conflicting values"));
+}
+
+#[test]
+fn test_resolver_not_called_when_missing() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(default)]
+        #[duplicates(resolve = "resolvers::reject_conflicts")]
+        pub value: i32,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(test_struct.value, 0);
+}
+
+#[test]
+fn test_resolver_with_skip_invalid() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(resolve = "resolvers::reject_conflicts", skip_invalid)]
+        pub value: i32,
+    }
+
+    let data = r#"
+        {
+            "value": 1,
+            "value": "invalid",
+            "value": 1
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.value, 1);
+}
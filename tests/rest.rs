@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate, DuplicateMap};
+
+#[test]
+fn test_unknown_keys_captured() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub name: String,
+
+        #[duplicates(rest)]
+        pub rest: DuplicateMap<String, serde_json::Value>,
+    }
+
+    let data = r#"
+        {
+            "name": "first",
+            "colour": "brown",
+            "name": "second",
+            "size": 3,
+            "colour": "black"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.name, "first".to_owned());
+    assert_eq!(
+        test_struct.rest.into_vec(),
+        vec![
+            ("colour".to_owned(), serde_json::json!("brown")),
+            ("size".to_owned(), serde_json::json!(3)),
+            ("colour".to_owned(), serde_json::json!("black")),
+        ]
+    );
+}
+
+#[test]
+fn test_aliases_not_captured() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "type")]
+        pub breed: String,
+
+        #[duplicates(rest)]
+        pub rest: DuplicateMap<String, String>,
+    }
+
+    let data = r#"
+        {
+            "type": "Labrador",
+            "owner": "Alice"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.breed, "Labrador".to_owned());
+    assert_eq!(test_struct.rest.get_first("owner"), Some(&"Alice".to_owned()));
+    assert_eq!(test_struct.rest.len(), 1);
+}
+
+#[test]
+fn test_no_unknown_keys() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub value: u32,
+
+        #[duplicates(rest)]
+        pub rest: DuplicateMap<String, u32>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "value": 1 }"#).unwrap();
+
+    assert!(test_struct.rest.is_empty());
+}
+
+#[test]
+fn test_typed_values() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub id: String,
+
+        #[duplicates(rest)]
+        pub scores: DuplicateMap<String, u32>,
+    }
+
+    let data = r#"{ "id": "a", "maths": 1, "maths": 2 }"#;
+    let invalid = r#"{ "id": "a", "maths": "n/a" }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.scores.get_all("maths").copied().collect::<Vec<_>>(), vec![1, 2]);
+    assert!(serde_json::from_str::<TestStruct>(invalid).is_err());
+}
+
+#[test]
+fn test_nested_duplicates_captured() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(rest)]
+        pub rest: DuplicateMap<String, DuplicateMap<String, u32>>,
+    }
+
+    let data = r#"{ "nested": { "a": 1, "a": 2 } }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.rest.get_first("nested").unwrap().count("a"), 2);
+}
+
+#[test]
+fn test_any_map_type() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub value: u32,
+
+        #[duplicates(rest)]
+        pub rest: BTreeMap<String, u32>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "value": 1, "other": 2 }"#).unwrap();
+
+    assert_eq!(test_struct.rest, BTreeMap::from([("other".to_owned(), 2)]));
+}
@@ -0,0 +1,258 @@
+use std::collections::BTreeMap;
+
+use serde_deserialize_duplicates::{
+    DeserializeFirstDuplicate, DeserializeLastDuplicate, DuplicateMap, KeyOrder, SerializeDuplicates,
+};
+
+#[test]
+fn test_collect_keeps_every_occurrence() {
+    #[derive(SerializeDuplicates, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(collect)]
+        pub values: Vec<u32>,
+
+        pub name: String,
+    }
+
+    let data = r#"
+        {
+            "values": 1,
+            "name": "a",
+            "values": 2,
+            "name": "b",
+            "values": 3
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.values, vec![1, 2, 3]);
+    assert_eq!(test_struct.name, "b".to_owned());
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), r#"{"values":1,"values":2,"values":3,"name":"b"}"#);
+}
+
+#[test]
+fn test_collect_single_occurrence_is_one_element() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(collect)]
+        pub tags: Vec<Vec<String>>,
+    }
+
+    let data = r#"{"tags":["a","b"]}"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.tags, vec![vec!["a".to_owned(), "b".to_owned()]]);
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), data);
+}
+
+#[test]
+fn test_collect_missing_field() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(collect)]
+        pub values: Vec<u32>,
+
+        #[duplicates(collect)]
+        #[serde(default)]
+        pub others: Vec<u32>,
+    }
+
+    assert!(serde_json::from_str::<TestStruct>(r#"{ "others": 1 }"#).is_err());
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "values": 1 }"#).unwrap();
+
+    assert!(test_struct.others.is_empty());
+}
+
+#[test]
+fn test_round_trip_with_aliases_and_rename() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(collect)]
+        #[serde(rename = "Value", alias = "val")]
+        pub value: Vec<String>,
+
+        pub id: u32,
+    }
+
+    let data = r#"
+        {
+            "id": 1,
+            "Value": "a",
+            "val": "b"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), r#"{"Value":"a","Value":"b","id":1}"#);
+}
+
+#[test]
+fn test_round_trip_rest() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub id: u32,
+
+        #[duplicates(rest)]
+        pub rest: DuplicateMap<String, serde_json::Value>,
+    }
+
+    let data = r#"{"id":1,"a":1,"b":{"c":2},"a":3}"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), data);
+}
+
+#[test]
+fn test_round_trip_extra() {
+    #[derive(SerializeDuplicates, DeserializeLastDuplicate)]
+    struct TestStruct {
+        pub id: u32,
+
+        #[duplicates(extra)]
+        pub extras: BTreeMap<String, u32>,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{"id":1,"b":1,"a":2,"b":3}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), r#"{"id":1,"a":2,"b":3}"#);
+}
+
+#[test]
+fn test_metadata_fields_not_serialized() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "type")]
+        pub breed: String,
+
+        #[duplicates(source_of = "breed")]
+        pub breed_key: String,
+
+        #[duplicates(count_of = "breed")]
+        pub breed_count: usize,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{ "type": "Labrador", "breed": "Retriever" }"#).unwrap();
+
+    assert_eq!(test_struct.breed_key, "type".to_owned());
+    assert_eq!(test_struct.breed_count, 2);
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), r#"{"breed":"Labrador"}"#);
+}
+
+#[test]
+fn test_nested_round_trip() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct Inner {
+        #[duplicates(collect)]
+        pub x: Vec<u32>,
+    }
+
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct Outer {
+        #[duplicates(collect)]
+        pub inner: Vec<Inner>,
+    }
+
+    let data = r#"{"inner":{"x":1,"x":2},"inner":{"x":3}}"#;
+
+    let outer: Outer = serde_json::from_str(data).unwrap();
+
+    assert_eq!(outer.inner.len(), 2);
+    assert_eq!(outer.inner[0].x, vec![1, 2]);
+    assert_eq!(serde_json::to_string(&outer).unwrap(), data);
+}
+
+#[test]
+fn test_key_order_replays_interleaved_keys() {
+    #[derive(SerializeDuplicates, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(collect)]
+        pub a: Vec<u32>,
+
+        pub b: u32,
+
+        #[duplicates(key_order)]
+        pub order: KeyOrder,
+    }
+
+    let data = r#"{"a":1,"b":2,"a":3}"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.order.iter().map(|entry| entry.key.as_str()).collect::<Vec<_>>(), ["a", "b", "a"]);
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), data);
+}
+
+#[test]
+fn test_key_order_writes_value_under_selected_key() {
+    #[derive(SerializeDuplicates, DeserializeLastDuplicate)]
+    struct TestStruct {
+        pub name: String,
+
+        #[serde(alias = "type")]
+        pub breed: String,
+
+        #[duplicates(key_order)]
+        pub order: KeyOrder,
+    }
+
+    let test_struct: TestStruct =
+        serde_json::from_str(r#"{"breed":"Retriever","name":"Rex","type":"Labrador"}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), r#"{"name":"Rex","type":"Labrador"}"#);
+}
+
+#[test]
+fn test_key_order_replays_rest_entries() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub name: String,
+
+        #[duplicates(rest)]
+        pub rest: DuplicateMap<String, u32>,
+
+        #[duplicates(key_order)]
+        pub order: KeyOrder,
+    }
+
+    let data = r#"{"x":1,"name":"a","y":2,"x":3}"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), data);
+}
+
+#[test]
+fn test_key_order_writes_missing_entries_last() {
+    #[derive(SerializeDuplicates, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(default)]
+        pub count: u32,
+
+        pub name: String,
+
+        #[duplicates(key_order)]
+        pub order: KeyOrder,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{"name":"a"}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), r#"{"name":"a","count":0}"#);
+}
+
+#[test]
+fn test_without_key_order_groups_by_field() {
+    #[derive(SerializeDuplicates, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(collect)]
+        pub a: Vec<u32>,
+
+        pub b: u32,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str(r#"{"a":1,"b":2,"a":3}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&test_struct).unwrap(), r#"{"a":1,"a":3,"b":2}"#);
+}
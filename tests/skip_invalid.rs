@@ -0,0 +1,132 @@
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+#[test]
+fn test_first_skips_invalid() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub count: i32,
+    }
+
+    let data = r#"
+        {
+            "count": "n/a",
+            "count": 5,
+            "count": 6
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 5);
+}
+
+#[test]
+fn test_last_skips_invalid() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub count: i32,
+    }
+
+    let data = r#"
+        {
+            "count": 5,
+            "count": 6,
+            "count": "n/a"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 6);
+}
+
+#[test]
+fn test_skips_invalid_across_aliases() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "amount")]
+        #[duplicates(skip_invalid)]
+        pub count: i32,
+    }
+
+    let data = r#"
+        {
+            "count": null,
+            "amount": 7
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 7);
+}
+
+#[test]
+fn test_fails_when_no_occurrence_is_valid() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub count: i32,
+    }
+
+    let data = r#"
+        {
+            "count": "n/a",
+            "count": "unknown"
+        }"#;
+
+    let error = serde_json::from_str::<TestStruct>(data).unwrap_err();
+
+    assert!(error.to_string().contains("no occurrence of field `count`"));
+}
+
+#[test]
+fn test_missing_skip_invalid_field() {
+    #[derive(Debug, Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub count: i32,
+    }
+
+    let error = serde_json::from_str::<TestStruct>("{}").unwrap_err();
+
+    assert!(error.to_string().contains("missing field `count`"));
+}
+
+#[test]
+fn test_skip_invalid_with_default() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[serde(default)]
+        #[duplicates(skip_invalid)]
+        pub count: i32,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(test_struct.count, 0);
+}
+
+#[test]
+fn test_nested_duplicates_kept_for_inner_struct() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct Inner {
+        pub a: i32,
+    }
+
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[duplicates(skip_invalid)]
+        pub inner: Inner,
+    }
+
+    let data = r#"
+        {
+            "inner": "n/a",
+            "inner": { "a": 1, "a": 2 }
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.inner.a, 1);
+}
@@ -0,0 +1,137 @@
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+
+#[test]
+fn test_alias_recorded() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "type")]
+        pub breed: String,
+
+        #[duplicates(source_of = "breed")]
+        pub breed_key: String,
+    }
+
+    let data = r#"
+        {
+            "type": "Labrador",
+            "breed": "Retriever"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.breed, "Labrador".to_owned());
+    assert_eq!(test_struct.breed_key, "type".to_owned());
+}
+
+#[test]
+fn test_last_alias_recorded() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        #[duplicates(source_of = "breed")]
+        pub breed_key: Option<String>,
+
+        #[serde(alias = "type")]
+        pub breed: String,
+    }
+
+    let data = r#"
+        {
+            "type": "Labrador",
+            "breed": "Retriever"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.breed, "Retriever".to_owned());
+    assert_eq!(test_struct.breed_key, Some("breed".to_owned()));
+}
+
+#[test]
+fn test_missing_field_source() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(default)]
+        pub breed: String,
+
+        #[duplicates(source_of = "breed")]
+        pub breed_key: Option<String>,
+
+        #[duplicates(source_of = "breed")]
+        pub breed_key_string: String,
+    }
+
+    let test_struct: TestStruct = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(test_struct.breed_key, None);
+    assert_eq!(test_struct.breed_key_string, String::new());
+}
+
+#[test]
+fn test_skipped_invalid_source() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "amount")]
+        #[duplicates(skip_invalid)]
+        pub count: i32,
+
+        #[duplicates(source_of = "count")]
+        pub count_key: String,
+    }
+
+    let data = r#"
+        {
+            "count": "n/a",
+            "amount": 3
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 3);
+    assert_eq!(test_struct.count_key, "amount".to_owned());
+}
+
+#[test]
+fn test_combined_occurrences_have_no_source() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "hits")]
+        #[duplicates(sum)]
+        pub count: i32,
+
+        #[duplicates(source_of = "count")]
+        pub count_key: Option<String>,
+    }
+
+    let data = r#"
+        {
+            "count": 1,
+            "hits": 2
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.count, 3);
+    assert_eq!(test_struct.count_key, None);
+}
+
+#[test]
+fn test_source_field_not_read_from_data() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub breed: String,
+
+        #[duplicates(source_of = "breed")]
+        pub breed_key: String,
+    }
+
+    let data = r#"
+        {
+            "breed": "Labrador",
+            "breed_key": "ignored"
+        }"#;
+
+    let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+    assert_eq!(test_struct.breed_key, "breed".to_owned());
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "tracing")]
+
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use serde_deserialize_duplicates::{DeserializeFirstDuplicate, DeserializeLastDuplicate};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Debug, Default, PartialEq)]
+struct DiscardedEvent {
+    type_name: String,
+    field: String,
+    key: String,
+    occurrence: u64,
+}
+
+impl Visit for DiscardedEvent {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "type_name" => self.type_name = value.to_owned(),
+            "field" => self.field = value.to_owned(),
+            "key" => self.key = value.to_owned(),
+            _ => {}
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "occurrence" {
+            self.occurrence = value;
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+#[derive(Clone, Default)]
+struct CapturingSubscriber {
+    events: Arc<Mutex<Vec<DiscardedEvent>>>,
+}
+
+impl Subscriber for CapturingSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut discarded_event = DiscardedEvent::default();
+        event.record(&mut discarded_event);
+        self.events.lock().unwrap().push(discarded_event);
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+fn capture_events<T>(deserialize: impl FnOnce() -> T) -> (T, Vec<DiscardedEvent>) {
+    let subscriber = CapturingSubscriber::default();
+    let events = subscriber.events.clone();
+    let value = tracing::subscriber::with_default(subscriber, deserialize);
+    let events = std::mem::take(&mut *events.lock().unwrap());
+
+    (value, events)
+}
+
+#[test]
+fn test_discarded_occurrences_traced() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        #[serde(alias = "type")]
+        pub breed: String,
+    }
+
+    let data = r#"
+        {
+            "breed": "Labrador",
+            "type": "Poodle",
+            "breed": "Beagle"
+        }"#;
+
+    let (test_struct, events) = capture_events(|| serde_json::from_str::<TestStruct>(data).unwrap());
+
+    assert_eq!(test_struct.breed, "Labrador".to_owned());
+    assert_eq!(
+        events,
+        vec![
+            DiscardedEvent {
+                type_name: "TestStruct".to_owned(),
+                field: "breed".to_owned(),
+                key: "type".to_owned(),
+                occurrence: 1,
+            },
+            DiscardedEvent {
+                type_name: "TestStruct".to_owned(),
+                field: "breed".to_owned(),
+                key: "breed".to_owned(),
+                occurrence: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_last_occurrence_kept() {
+    #[derive(Serialize, DeserializeLastDuplicate)]
+    struct TestStruct {
+        pub value: u32,
+    }
+
+    let data = r#"{ "value": 1, "value": 2 }"#;
+
+    let (test_struct, events) = capture_events(|| serde_json::from_str::<TestStruct>(data).unwrap());
+
+    assert_eq!(test_struct.value, 2);
+    assert_eq!(
+        events,
+        vec![DiscardedEvent {
+            type_name: "TestStruct".to_owned(),
+            field: "value".to_owned(),
+            key: "value".to_owned(),
+            occurrence: 0,
+        }]
+    );
+}
+
+#[test]
+fn test_no_events_without_duplicates() {
+    #[derive(Serialize, DeserializeFirstDuplicate)]
+    struct TestStruct {
+        pub value: u32,
+    }
+
+    let (_, events) = capture_events(|| serde_json::from_str::<TestStruct>(r#"{ "value": 1 }"#).unwrap());
+
+    assert!(events.is_empty());
+}